@@ -75,6 +75,7 @@ pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type,
             TypePattern::Bool => {}
             TypePattern::CChar => {}
             TypePattern::APIVersion => {}
+            TypePattern::Bitflags(_) => {}
             TypePattern::Vec(x) => {
                 write_type_definition_composite(i, w, x.composite_type())?;
                 w.newline()?;
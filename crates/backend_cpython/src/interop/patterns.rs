@@ -140,6 +140,7 @@ pub fn write_patterns(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
         match pattern {
             LibraryPattern::Service(x) => write_pattern_class(i, w, x)?,
             LibraryPattern::Builtins(_) => { /* TODO */ }
+            LibraryPattern::Batch(_) => {}
             _ => panic!("Pattern not explicitly handled"),
         }
     }
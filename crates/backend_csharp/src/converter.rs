@@ -56,6 +56,7 @@ pub fn field_to_type(x: &Type) -> String {
             TypePattern::CChar => "sbyte".to_string(),
             TypePattern::APIVersion => field_to_type(&x.fallback_type()),
             TypePattern::Vec(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Bitflags(x) => x.rust_name().to_string(),
             TypePattern::AsyncCallback(_) => todo!("Async callbacks not supported in fields"),
         },
     }
@@ -87,6 +88,7 @@ pub fn field_to_type_unmanaged(x: &Type) -> String {
             TypePattern::APIVersion => field_to_type(&x.fallback_type()),
             TypePattern::AsyncCallback(_) => todo!("Async callbacks not supported in fields"),
             TypePattern::Vec(x) => format!("{}.Unmanaged", x.composite_type().rust_name()),
+            TypePattern::Bitflags(x) => x.rust_name().to_string(),
         },
     }
 }
@@ -147,6 +149,7 @@ pub fn param_to_type(x: &Type) -> String {
             TypePattern::Option(x) => x.the_enum().rust_name().to_string(),
             TypePattern::Result(x) => x.the_enum().rust_name().to_string(),
             TypePattern::Vec(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Bitflags(x) => x.rust_name().to_string(),
             TypePattern::NamedCallback(x) => x.name().to_string(),
             TypePattern::AsyncCallback(_) => "AsyncCallbackCommonNative".to_string(),
             TypePattern::Bool => "Bool".to_string(),
@@ -172,6 +175,7 @@ pub fn param_to_type_overloaded(x: &Type) -> String {
 pub fn param_to_managed(x: &Parameter) -> String {
     match x.the_type() {
         Type::Primitive(_) => x.name().to_string(),
+        Type::Pattern(TypePattern::Bitflags(_)) => x.name().to_string(),
         Type::ReadPointer(_) => x.name().to_string(),
         Type::ReadWritePointer(_) => x.name().to_string(),
         _ if is_reusable(x.the_type()) => format!("{}.ToManaged()", x.name()),
@@ -186,6 +190,7 @@ pub fn field_to_managed(x: &Field) -> String {
         Type::ReadPointer(_) => x.name().to_string(),
         Type::ReadWritePointer(_) => x.name().to_string(),
         Type::Pattern(TypePattern::CStrPointer) => "string.Empty".to_string(),
+        Type::Pattern(TypePattern::Bitflags(_)) => x.name().to_string(),
         _ if is_reusable(x.the_type()) => format!("{}.ToManaged()", x.name()),
         _ => format!("{}.IntoManaged()", x.name()),
     }
@@ -199,6 +204,7 @@ pub fn field_to_unmanaged(x: &Field) -> String {
         Type::ReadPointer(_) => x.name().to_string(),
         Type::ReadWritePointer(_) => x.name().to_string(),
         Type::Pattern(TypePattern::CStrPointer) => "IntPtr.Zero".to_string(),
+        Type::Pattern(TypePattern::Bitflags(_)) => name.to_string(),
         Type::Pattern(TypePattern::NamedCallback(_)) => format!("{name}?.ToUnmanaged() ?? default"),
         _ if is_reusable(x.the_type()) => format!("{name}.ToUnmanaged()"),
         _ => format!("{name}.IntoUnmanaged()"),
@@ -213,6 +219,7 @@ pub fn field_as_unmanaged(x: &Field) -> String {
         Type::ReadPointer(_) => x.name().to_string(),
         Type::ReadWritePointer(_) => x.name().to_string(),
         Type::Pattern(TypePattern::CStrPointer) => "IntPtr.Zero".to_string(),
+        Type::Pattern(TypePattern::Bitflags(_)) => name.to_string(),
         Type::Pattern(TypePattern::NamedCallback(_)) => format!("{name}?.ToUnmanaged() ?? default"),
         _ if is_reusable(x.the_type()) => format!("{name}.ToUnmanaged()"),
         _ => format!("{name}.AsUnmanaged()"),
@@ -242,6 +249,7 @@ pub fn rval_to_type_sync(x: &Type) -> String {
             TypePattern::CChar => "sbyte".to_string(),
             TypePattern::APIVersion => rval_to_type_sync(&x.fallback_type()),
             TypePattern::Vec(x) => x.composite_type().rust_name().to_string(),
+            TypePattern::Bitflags(x) => x.rust_name().to_string(),
             TypePattern::AsyncCallback(_) => panic!("AsyncCallback not supported in rvals"),
         },
     }
@@ -355,6 +363,7 @@ pub fn is_reusable(t: &Type) -> bool {
             TypePattern::NamedCallback(_) => true,
             TypePattern::AsyncCallback(_) => true,
             TypePattern::Vec(_) => false,
+            TypePattern::Bitflags(_) => true,
         },
     }
 }
@@ -395,6 +404,7 @@ pub fn has_dispose(t: &Type) -> bool {
             TypePattern::NamedCallback(_) => true,
             TypePattern::AsyncCallback(_) => true,
             TypePattern::Vec(_) => true,
+            TypePattern::Bitflags(_) => false,
         },
     }
 }
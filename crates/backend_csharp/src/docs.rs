@@ -48,9 +48,9 @@ impl<'a> Markdown<'a> {
         indented!(w, r"### Classes")?;
         indented!(w, r"Methods operating on common state.")?;
 
-        for pattern in self.interop.inventory.patterns().iter().map(|x| match x {
-            LibraryPattern::Service(s) => s,
-            _ => panic!("Pattern not explicitly handled"),
+        for pattern in self.interop.inventory.patterns().iter().filter_map(|x| match x {
+            LibraryPattern::Service(s) => Some(s),
+            _ => None,
         }) {
             let prefix = pattern.common_prefix();
             let doc = pattern.the_type().meta().docs().lines().first().cloned().unwrap_or_default();
@@ -232,9 +232,9 @@ impl<'a> Markdown<'a> {
     fn write_services(&self, w: &mut IndentWriter) -> Result<(), Error> {
         indented!(w, r"# Classes")?;
 
-        for pattern in self.interop.inventory.patterns().iter().map(|x| match x {
-            LibraryPattern::Service(s) => s,
-            _ => panic!("Pattern not explicitly handled"),
+        for pattern in self.interop.inventory.patterns().iter().filter_map(|x| match x {
+            LibraryPattern::Service(s) => Some(s),
+            _ => None,
         }) {
             let prefix = pattern.common_prefix();
             let doc = pattern.the_type().meta().docs().lines();
@@ -8,23 +8,23 @@ pub mod namespace;
 pub mod patterns;
 pub mod types;
 
-use crate::converter::param_to_type;
+use crate::converter::{is_reusable, param_to_type};
 use crate::interop::builtins::write_builtins;
 use crate::interop::class::{write_class_context, write_native_lib_string};
 use crate::interop::constants::write_constants;
 use crate::interop::docs::write_file_header_comments;
 use crate::interop::functions::write_functions;
 use crate::interop::imports::write_imports;
-use crate::interop::namespace::write_namespace_context;
+use crate::interop::namespace::{write_builtins_namespace_context, write_namespace_context};
 use crate::interop::patterns::abi_guard::write_abi_guard;
 use crate::interop::patterns::asynk::write_pattern_async_trampoline_initializers;
 use crate::interop::patterns::write_patterns;
-use crate::interop::types::write_type_definitions;
+use crate::interop::types::{write_builtin_type_definitions, write_type_definitions};
 use derive_builder::Builder;
 use interoptopus::backend::IndentWriter;
 use interoptopus::backend::{NamespaceMappings, is_global_type};
 use interoptopus::inventory::{Bindings, Inventory};
-use interoptopus::lang::{Constant, Function, Meta, Signature, Type};
+use interoptopus::lang::{Composite, Constant, Function, Meta, Signature, Type};
 use interoptopus::pattern::TypePattern;
 use interoptopus::{Error, indented};
 
@@ -99,6 +99,17 @@ impl Visibility {
     }
 }
 
+/// Whether and how object pools are generated for types marked `#[ffi_type(poolable)]`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Pooling {
+    /// No pools are generated.
+    Disabled,
+    /// Pools are generated, but must only be used from a single thread.
+    SingleThreaded,
+    /// Pools are generated and can be shared between threads.
+    ThreadSafe,
+}
+
 impl Default for Interop {
     fn default() -> Self {
         Self {
@@ -107,12 +118,18 @@ impl Default for Interop {
             class: "Interop".to_string(),
             class_constants: None,
             dll_name: "library".to_string(),
+            entry_point_prefix: String::new(),
+            entry_point_suffix: String::new(),
             namespace_mappings: NamespaceMappings::new("My.Company"),
             namespace_id: String::new(),
+            builtins_namespace: None,
             visibility_types: Visibility::AsDeclared,
             write_types: WriteTypes::NamespaceAndInteroptopusGlobal,
             debug: false,
             doc_hints: true,
+            enum_parse_ignore_case: false,
+            pooling: Pooling::Disabled,
+            string_stackalloc_threshold: 1024,
         }
     }
 }
@@ -134,12 +151,33 @@ pub struct Interop {
     /// DLL to load, e.g., `my_library`.
     #[builder(setter(into))]
     dll_name: String,
+    /// Prepended to the native `EntryPoint` of every function, e.g., `lib_` (default ""). C# method names are not affected.
+    ///
+    /// Interoptopus builtins such as `interoptopus_string_create` keep their fixed symbol names.
+    #[builder(setter(into))]
+    entry_point_prefix: String,
+    /// Appended to the native `EntryPoint` of every function, e.g., `_v2` (default "").
+    #[builder(setter(into))]
+    entry_point_suffix: String,
     /// Maps which namespace id belongs into which FQN (e.g., "common" => "MyCompany.Common").
     #[builder(setter(into))]
     namespace_mappings: NamespaceMappings,
     /// Namespace ID of _this_ namespace to write (default "").
     #[builder(setter(into))]
     namespace_id: String,
+    /// If set, Interoptopus builtins (e.g., `Utf8String`, `Bool`, `SliceU8`) go into this shared
+    /// namespace, e.g., `Interoptopus.Runtime`, instead of the current one.
+    ///
+    /// This allows multiple generated libraries to share one copy of these types: emit them
+    /// once via [`WriteTypes::NamespaceAndInteroptopusGlobal`], and use [`WriteTypes::Namespace`]
+    /// with the same builtins namespace for all other libraries.
+    ///
+    /// Note the shared `InteropHelper` of these builtins still binds `Interop.NativeLib` of the
+    /// library that emitted them. Owned builtins (e.g., `Utf8String`) are therefore always allocated
+    /// and freed through that library, so passing them to another library is only sound if all
+    /// libraries use the same global allocator.
+    #[builder(setter(into))]
+    builtins_namespace: Option<String>,
     /// Sets the visibility access modifiers for generated types.
     #[builder(setter(into))]
     pub(crate) visibility_types: Visibility,
@@ -150,6 +188,17 @@ pub struct Interop {
     debug: bool,
     /// Enrich user-provided item documentation with safety warnings and proper API use hints.
     doc_hints: bool,
+    /// Whether the generated `TryParse` of unit-only enums ignores case when matching variant names.
+    enum_parse_ignore_case: bool,
+    /// Generates `FooPool` classes for poolable types; only affects types emitted as C# classes.
+    #[builder(setter(into))]
+    pooling: Pooling,
+    /// Strings are converted to `Utf8String` via a stack buffer if their worst-case encoded size fits this many bytes,
+    /// longer ones via a pooled array.
+    ///
+    /// The bound is `Encoding.UTF8.GetMaxByteCount(length)`, i.e., about 3 bytes per UTF-16 char, so the default of
+    /// 1024 allows strings of up to ~340 chars on the stack. Values above `int.MaxValue` are clamped.
+    string_stackalloc_threshold: u32,
     pub(crate) inventory: Inventory,
 }
 
@@ -211,6 +260,11 @@ impl Interop {
         }
     }
 
+    /// True if a pool should be written for this composite.
+    pub(crate) fn should_emit_pool(&self, composite: &Composite) -> bool {
+        self.pooling != Pooling::Disabled && composite.is_poolable() && !is_reusable(&composite.to_type())
+    }
+
     #[allow(dead_code)]
     fn has_emittable_marshallers(&self, types: &[Type]) -> bool {
         types.iter().any(|x| self.should_emit_marshaller(x))
@@ -225,6 +279,15 @@ impl Interop {
         constants.iter().any(|x| self.should_emit_by_meta(x.meta()))
     }
 
+    /// The native symbol name to bind for the given function.
+    pub(crate) fn entry_point(&self, function: &Function) -> String {
+        if function.name().starts_with("interoptopus_") {
+            return function.name().to_string();
+        }
+
+        format!("{}{}{}", self.entry_point_prefix, function.name(), self.entry_point_suffix)
+    }
+
     #[must_use]
     fn should_emit_by_meta(&self, meta: &Meta) -> bool {
         meta.module() == self.namespace_id
@@ -285,9 +348,26 @@ impl Interop {
         })
     }
 
+    /// Checks whether the given type is an Interoptopus builtin, e.g., `Bool` or `SliceU8`.
+    #[must_use]
+    fn is_builtin_type(&self, t: &Type) -> bool {
+        matches!(t, Type::Pattern(_)) && is_global_type(t)
+    }
+
+    /// Checks whether for the given type and the current file a builtin type definition should be
+    /// emitted into the builtins namespace.
+    #[must_use]
+    fn should_emit_builtin_type(&self, t: &Type) -> bool {
+        self.builtins_namespace.is_some() && self.write_types.write_interoptopus_globals() && self.is_builtin_type(t)
+    }
+
     /// Checks whether for the given type and the current file a type definition should be emitted.
     #[must_use]
     fn should_emit_by_type(&self, t: &Type) -> bool {
+        if self.builtins_namespace.is_some() && self.is_builtin_type(t) {
+            return false;
+        }
+
         if self.write_types == WriteTypes::All {
             return true;
         }
@@ -317,6 +397,7 @@ impl Interop {
                 TypePattern::NamedCallback(x) => self.should_emit_by_meta(x.meta()),
                 TypePattern::AsyncCallback(x) => self.should_emit_by_meta(x.meta()),
                 TypePattern::Vec(x) => self.should_emit_by_meta(x.meta()),
+                TypePattern::Bitflags(x) => self.should_emit_by_meta(x.meta()),
                 TypePattern::Utf8String(_) => false,
             },
         }
@@ -380,12 +461,26 @@ impl Interop {
             w.newline()?;
             write_patterns(self, w)?;
 
-            w.newline()?;
-            write_builtins(self, w)?;
+            if self.builtins_namespace.is_none() {
+                w.newline()?;
+                write_builtins(self, w)?;
+            }
 
             Ok(())
         })?;
 
+        if self.builtins_namespace.is_some() && self.write_types.write_interoptopus_globals() {
+            w.newline()?;
+            write_builtins_namespace_context(self, w, |w| {
+                write_builtin_type_definitions(self, w)?;
+
+                w.newline()?;
+                write_builtins(self, w)?;
+
+                Ok(())
+            })?;
+        }
+
         Ok(())
     }
 }
@@ -28,12 +28,13 @@ pub fn write_function(i: &Interop, w: &mut IndentWriter, function: &Function, wr
     write_function_declaration(i, w, function, false)?;
     w.newline()?;
     write_function_overload(i, w, function, write_for)?;
+    write_function_return_via_out(i, w, function, write_for)?;
 
     Ok(())
 }
 
-pub fn write_function_annotation(_i: &Interop, w: &mut IndentWriter, function: &Function) -> Result<(), Error> {
-    indented!(w, r#"[LibraryImport(NativeLib, EntryPoint = "{}")]"#, function.name())?;
+pub fn write_function_annotation(i: &Interop, w: &mut IndentWriter, function: &Function) -> Result<(), Error> {
+    indented!(w, r#"[LibraryImport(NativeLib, EntryPoint = "{}")]"#, i.entry_point(function))?;
 
     if *function.signature().rval() == Type::Primitive(Primitive::Bool) {
         indented!(w, r"[return: MarshalAs(UnmanagedType.U1)]")?;
@@ -53,8 +54,8 @@ pub fn write_function_declaration(i: &Interop, w: &mut IndentWriter, function: &
     let native = i.has_custom_marshalled_delegate(function.signature());
     let visibility = "public ";
 
-    for p in function.signature().params() {
-        let the_type = param_to_type(p.the_type());
+    for (n, p) in function.signature().params().iter().enumerate() {
+        let the_type = param_to_type_out_aware(function, n, p.the_type());
         let name = p.name();
 
         if native && matches!(p.the_type(), Type::FnPointer(_) | Type::Pattern(TypePattern::NamedCallback(_))) {
@@ -95,12 +96,18 @@ pub fn write_function_overload(i: &Interop, w: &mut IndentWriter, function: &Fun
     let rval = rval_to_type_async(&sugared_return_type(function));
 
     let mut params = Vec::new();
-    for p in function.signature().params() {
+    for (n, p) in function.signature().params().iter().enumerate() {
         let name = p.name();
-        let native = param_to_type_overloaded(p.the_type());
+        let native = if is_out_param(function, n) {
+            param_to_type_out_aware(function, n, p.the_type())
+        } else {
+            param_to_type_overloaded(p.the_type())
+        };
 
         let mut fallback = || {
-            if native.contains("ref ") {
+            if native.starts_with("out ") {
+                to_invoke.push(format!("out {name}"));
+            } else if native.contains("ref ") {
                 to_invoke.push(format!("ref {name}"));
             } else {
                 to_invoke.push(name.to_string());
@@ -185,3 +192,66 @@ pub fn write_function_overload(i: &Interop, w: &mut IndentWriter, function: &Fun
 
     indented!(w, r"}}")
 }
+
+/// For functions returning via out, writes an overload returning the out-parameter by value.
+pub fn write_function_return_via_out(i: &Interop, w: &mut IndentWriter, function: &Function, write_for: WriteFor) -> Result<(), Error> {
+    i.debug(w, "write_function_return_via_out")?;
+
+    let Some(out_type) = function.out_return_type() else {
+        return Ok(());
+    };
+
+    let name = function_name(function, FunctionNameFlavor::RawFFIName);
+    let rval = rval_to_type_sync(out_type);
+    let in_params = &function.signature().params()[..function.signature().params().len() - 1];
+
+    let mut params = Vec::new();
+    let mut to_invoke = Vec::new();
+
+    for p in in_params {
+        let the_type = param_to_type(p.the_type());
+        let name = p.name();
+
+        if the_type.starts_with("ref ") {
+            to_invoke.push(format!("ref {name}"));
+        } else {
+            to_invoke.push(name.to_string());
+        }
+
+        params.push(format!("{the_type} {name}"));
+    }
+
+    to_invoke.push("out var rval".to_string());
+
+    let signature = format!(r"public static {rval} {name}({})", params.join(", "));
+    if write_for == WriteFor::Docs {
+        indented!(w, r"{};", signature)?;
+        return Ok(());
+    }
+
+    if i.has_overloadable(function.signature()) {
+        w.newline()?;
+    }
+
+    write_documentation(w, function.meta().docs())?;
+    i.inline_hint(w, 0)?;
+    indented!(w, "{}", signature)?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"{name}({});", to_invoke.join(", "))?;
+    indented!(w, [()], r"return rval;")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
+fn is_out_param(function: &Function, index: usize) -> bool {
+    function.returns_via_out() && index + 1 == function.signature().params().len()
+}
+
+/// Like [`param_to_type`], but emits `out T` for the out-parameter of functions returning via out.
+fn param_to_type_out_aware(function: &Function, index: usize, x: &Type) -> String {
+    match function.out_return_type() {
+        Some(t) if is_out_param(function, index) => format!("out {}", param_to_type(t)),
+        _ => param_to_type(x),
+    }
+}
@@ -16,7 +16,9 @@ pub fn write_imports(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
     indented!(w, r"using System.Runtime.CompilerServices;")?;
 
     let mut namespace_imports = i.namespace_mappings.iter().map(|x| x.1.as_str().to_string()).collect::<Vec<_>>();
+    namespace_imports.extend(i.builtins_namespace.clone());
     namespace_imports.sort();
+    namespace_imports.dedup();
 
     for v in namespace_imports {
         indented!(w, r"using {v};")?;
@@ -14,3 +14,16 @@ pub fn write_namespace_context(i: &Interop, w: &mut IndentWriter, f: impl FnOnce
 
     indented!(w, r"}}")
 }
+
+pub fn write_builtins_namespace_context(i: &Interop, w: &mut IndentWriter, f: impl FnOnce(&mut IndentWriter) -> Result<(), Error>) -> Result<(), Error> {
+    i.debug(w, "write_builtins_namespace_context")?;
+    indented!(w, r"namespace {}", i.builtins_namespace.as_deref().unwrap_or_default())?;
+    indented!(w, r"{{")?;
+    w.indent();
+
+    f(w)?;
+
+    w.unindent();
+
+    indented!(w, r"}}")
+}
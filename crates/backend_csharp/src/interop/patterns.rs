@@ -1,5 +1,7 @@
 pub mod abi_guard;
 pub mod asynk;
+pub mod batch;
+pub mod bitflags;
 pub mod callbacks;
 pub mod options;
 pub mod services;
@@ -7,6 +9,7 @@ pub mod slices;
 pub mod vec;
 
 use crate::Interop;
+use crate::interop::patterns::batch::write_pattern_batch;
 use crate::interop::patterns::services::write_pattern_service;
 use interoptopus::Error;
 use interoptopus::backend::IndentWriter;
@@ -21,6 +24,11 @@ pub fn write_patterns(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
                 }
             }
             LibraryPattern::Builtins(_) => {}
+            LibraryPattern::Batch(batch) => {
+                if i.should_emit_by_meta(batch.submit().meta()) {
+                    write_pattern_batch(i, w, batch)?;
+                }
+            }
             _ => panic!("Pattern not explicitly handled"),
         }
     }
@@ -0,0 +1,141 @@
+use crate::converter::{field_to_type, function_name, param_to_type};
+use crate::interop::docs::write_documentation;
+use crate::{FunctionNameFlavor, Interop};
+use heck::ToUpperCamelCase;
+use interoptopus::backend::IndentWriter;
+use interoptopus::lang::Function;
+use interoptopus::pattern::batch::{BatchDefinition, opcode};
+use interoptopus::{Error, indented};
+
+/// Members of the batch class besides the command encoders.
+const BOOKKEEPING_MEMBERS: [&str; 4] = ["CommandCount", "SubmitBatch", "ClearCommands", "RecordCommand"];
+
+/// Writes a class recording calls to batchable functions into a buffer, executed at once by the submit function.
+///
+/// # Panics
+/// Panics if the encoder of a command collides with the class itself or any of its other members.
+pub fn write_pattern_batch(i: &Interop, w: &mut IndentWriter, batch: &BatchDefinition) -> Result<(), Error> {
+    i.debug(w, "write_pattern_batch")?;
+
+    let name = batch.name();
+    let args_structs = batch
+        .commands()
+        .iter()
+        .filter(|x| !x.signature().params().is_empty())
+        .map(|x| format!("{}Args", x.name().to_upper_camel_case()))
+        .collect::<Vec<_>>();
+
+    for command in batch.commands() {
+        let method = command.name().to_upper_camel_case();
+        let collides = method == name || BOOKKEEPING_MEMBERS.contains(&method.as_str()) || args_structs.contains(&method);
+        assert!(!collides, "Batch `{name}` can not emit `{method}` for `{}`, it collides with a generated member.", command.name());
+    }
+    let submit = function_name(batch.submit(), FunctionNameFlavor::RawFFIName);
+    let slice = batch.submit().signature().params().first().map(|x| param_to_type(x.the_type())).unwrap_or_default();
+
+    indented!(w, r"/// Records calls to batchable functions, executed by a single `{submit}` call on `SubmitBatch()`.")?;
+    indented!(w, r"{} partial class {name}", i.visibility_types.to_access_modifier())?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"private byte[] _buffer;")?;
+    indented!(w, [()], r"private int _length;")?;
+    indented!(w, [()], r"private uint _count;")?;
+    w.newline()?;
+    indented!(w, [()], r"public {name}(int capacity = 256)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"_buffer = new byte[capacity];")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    indented!(w, [()], r"/// The number of recorded commands.")?;
+    indented!(w, [()], r"public int CommandCount => (int) _count;")?;
+    w.newline()?;
+
+    for command in batch.commands() {
+        write_pattern_batch_command(i, w, command)?;
+        w.newline()?;
+    }
+
+    indented!(w, [()], r"/// Executes all recorded commands in a single call and clears this batch.")?;
+    indented!(w, [()], r"public unsafe void SubmitBatch()")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"if (_count == 0) return;")?;
+    indented!(w, [()()], r"var expected = _count;")?;
+    indented!(w, [()()], r"uint executed;")?;
+    indented!(w, [()()], r"fixed (byte* data = _buffer)")?;
+    indented!(w, [()()], r"{{")?;
+    indented!(w, [()()()], r"executed = {}.{submit}({slice}.From((IntPtr) data, (ulong) _length));", i.class)?;
+    indented!(w, [()()], r"}}")?;
+    indented!(w, [()()], r"ClearCommands();")?;
+    indented!(w, [()()], r"// Fewer executed commands mean the native side did not understand the buffer.")?;
+    indented!(w, [()()], r"if (executed != expected) throw new InteropException();")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    indented!(w, [()], r"/// Discards all recorded commands.")?;
+    indented!(w, [()], r"public void ClearCommands()")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"_length = 0;")?;
+    indented!(w, [()()], r"_count = 0;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"private void RecordCommand(uint opcode, int size)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"var required = _length + 8 + size;")?;
+    indented!(w, [()()], r"if (required > _buffer.Length) Array.Resize(ref _buffer, Math.Max(required, _buffer.Length * 2));")?;
+    indented!(w, [()()], r"Unsafe.WriteUnaligned(ref _buffer[_length], opcode);")?;
+    indented!(w, [()()], r"Unsafe.WriteUnaligned(ref _buffer[_length + 4], (uint) size);")?;
+    indented!(w, [()()], r"_length += 8;")?;
+    indented!(w, [()()], r"_count++;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"private void RecordCommand<T>(uint opcode, T args) where T : unmanaged")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"var size = Unsafe.SizeOf<T>();")?;
+    indented!(w, [()()], r"RecordCommand(opcode, size);")?;
+    indented!(w, [()()], r"Unsafe.WriteUnaligned(ref _buffer[_length], args);")?;
+    indented!(w, [()()], r"_length += size;")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    Ok(())
+}
+
+/// Writes the encoder method of a single command, and the struct holding its arguments.
+pub fn write_pattern_batch_command(i: &Interop, w: &mut IndentWriter, command: &Function) -> Result<(), Error> {
+    i.debug(w, "write_pattern_batch_command")?;
+
+    let method = command.name().to_upper_camel_case();
+    let args = format!("{method}Args");
+    let opcode = opcode(command.name());
+    let params = command.signature().params();
+    let declaration = params.iter().map(|x| format!("{} {}", field_to_type(x.the_type()), x.name())).collect::<Vec<_>>();
+
+    w.indent();
+    write_documentation(w, command.meta().docs())?;
+    w.unindent();
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public void {method}({})", declaration.join(", "))?;
+    indented!(w, [()], r"{{")?;
+    if params.is_empty() {
+        // Empty C# structs still occupy a byte, so commands without arguments have no payload.
+        indented!(w, [()()], r"RecordCommand(0x{opcode:08X}u, 0);")?;
+    } else {
+        let fields = params.iter().map(|x| format!("{0} = {0}", x.name())).collect::<Vec<_>>();
+        indented!(w, [()()], r"RecordCommand(0x{opcode:08X}u, new {args} {{ {} }});", fields.join(", "))?;
+    }
+    indented!(w, [()], r"}}")?;
+
+    if !params.is_empty() {
+        w.newline()?;
+        indented!(w, [()], r"[StructLayout(LayoutKind.Sequential)]")?;
+        indented!(w, [()], r"private struct {args}")?;
+        indented!(w, [()], r"{{")?;
+        for p in params {
+            indented!(w, [()()], r"public {} {};", field_to_type(p.the_type()), p.name())?;
+        }
+        indented!(w, [()], r"}}")?;
+    }
+
+    Ok(())
+}
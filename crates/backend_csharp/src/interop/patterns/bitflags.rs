@@ -0,0 +1,29 @@
+use crate::Interop;
+use crate::converter::primitive_to_type;
+use crate::interop::docs::write_documentation;
+use interoptopus::backend::IndentWriter;
+use interoptopus::lang::Primitive;
+use interoptopus::pattern::bitflags::BitflagsType;
+use interoptopus::{Error, indented};
+
+pub fn write_pattern_bitflags(i: &Interop, w: &mut IndentWriter, the_type: &BitflagsType) -> Result<(), Error> {
+    i.debug(w, "write_pattern_bitflags")?;
+
+    let name = the_type.rust_name();
+    let bits = primitive_to_type(the_type.bits());
+    let signed = matches!(the_type.bits(), Primitive::I8 | Primitive::I16 | Primitive::I32 | Primitive::I64);
+
+    write_documentation(w, the_type.meta().docs())?;
+    indented!(w, r"[Flags]")?;
+    indented!(w, r"{} enum {name} : {bits}", i.visibility_types.to_access_modifier())?;
+    indented!(w, r"{{")?;
+    for flag in the_type.flags() {
+        // Values are stored sign-extended, so signed flags need to be reinterpreted.
+        #[allow(clippy::cast_possible_wrap)]
+        let value = if signed { (flag.value() as i64).to_string() } else { flag.value().to_string() };
+        indented!(w, [()], r"{} = {value},", flag.name())?;
+    }
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
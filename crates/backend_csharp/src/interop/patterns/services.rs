@@ -2,16 +2,16 @@ use crate::converter::{field_to_type, function_name, param_to_type, param_to_typ
 use crate::interop::docs::write_documentation;
 use crate::utils::sugared_return_type;
 use crate::{FunctionNameFlavor, Interop};
+use heck::ToUpperCamelCase;
 use interoptopus::backend::{IndentWriter, WriteFor};
-use interoptopus::lang::{Function, Primitive, SugaredReturnType, Type};
+use interoptopus::lang::{Function, Parameter, Primitive, SugaredReturnType, Type};
 use interoptopus::pattern::TypePattern;
-use interoptopus::pattern::service::ServiceDefinition;
+use interoptopus::pattern::service::{ServiceDefinition, ServiceEvent};
 use interoptopus::{Error, indented};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MethodType {
     Ctor,
-    Dtor,
     Regular,
 }
 
@@ -33,10 +33,11 @@ pub fn write_pattern_service(i: &Interop, w: &mut IndentWriter, class: &ServiceD
         write_documentation(w, ctor.meta().docs())?;
         write_pattern_service_method(i, w, class, ctor, MethodType::Ctor, WriteFor::Code)?;
         w.newline()?;
+        write_pattern_service_try_ctor(i, w, class, ctor)?;
+        w.newline()?;
     }
 
-    // Dtor
-    write_pattern_service_method(i, w, class, class.destructor(), MethodType::Dtor, WriteFor::Code)?;
+    write_pattern_service_dispose(i, w, class)?;
     w.newline()?;
 
     for function in class.methods() {
@@ -46,6 +47,12 @@ pub fn write_pattern_service(i: &Interop, w: &mut IndentWriter, class: &ServiceD
         w.newline()?;
     }
 
+    write_pattern_service_events(i, w, class)?;
+    write_pattern_service_channel(i, w, class)?;
+
+    write_pattern_service_handles(i, w, class)?;
+    w.newline()?;
+
     indented!(w, r"public IntPtr Context => _context;")?;
 
     w.unindent();
@@ -56,6 +63,341 @@ pub fn write_pattern_service(i: &Interop, w: &mut IndentWriter, class: &ServiceD
     Ok(())
 }
 
+/// Writes non-throwing `TryXxx(..., out Service service, out Error error)` variants of the given constructor.
+///
+/// # Panics
+///
+/// Panics if the service already has a constructor or method named `TryXxx`.
+pub fn write_pattern_service_try_ctor(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition, ctor: &Function) -> Result<(), Error> {
+    i.debug(w, "write_pattern_service_try_ctor")?;
+
+    let context_type_name = class.the_type().rust_name();
+    let common_prefix = class.common_prefix();
+    let ctor_name = function_name(ctor, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix));
+    let method_to_invoke = function_name(ctor, FunctionNameFlavor::RawFFIName);
+    let error_type = ctor_error_type(ctor);
+    let (names, types, to_invoke) = params(ctor, MethodType::Ctor, false);
+
+    let try_name = format!("Try{ctor_name}");
+    let collision = class
+        .constructors()
+        .iter()
+        .chain(class.methods())
+        .find(|x| function_name(x, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix)) == try_name);
+    if let Some(x) = collision {
+        panic!("Service `{context_type_name}` can not emit `{try_name}` for `{}`, it collides with `{}`.", ctor.name(), x.name());
+    }
+
+    let mut forward = to_invoke.clone();
+    forward.push("out service".to_string());
+    forward.push("out _".to_string());
+
+    let mut short_args = names.iter().zip(types.iter()).map(|(n, t)| format!("{t} {n}")).collect::<Vec<_>>();
+    short_args.push(format!("out {context_type_name} service"));
+
+    let mut full_args = short_args.clone();
+    full_args.push(format!("out {error_type} error"));
+
+    indented!(w, r"/// Like `{ctor_name}` but returns `false` instead of throwing if the native constructor fails.")?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static bool {try_name}({}) => {try_name}({});", short_args.join(", "), forward.join(", "))?;
+    w.newline()?;
+    indented!(w, r"/// Like `{ctor_name}` but returns `false` and the native error instead of throwing if the constructor fails.")?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static bool {try_name}({})", full_args.join(", "))?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"service = null;")?;
+    indented!(w, [()], r"error = default;")?;
+    indented!(w, [()], r"var result = {}.{method_to_invoke}({});", i.class, to_invoke.join(", "))?;
+    indented!(w, [()], r"if (!result.IsOk)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"error = result.AsErr();")?;
+    indented!(w, [()()], r"return false;")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"service = new {context_type_name}();")?;
+    indented!(w, [()], r"service._context = result.AsOk();")?;
+    indented!(w, [()], r"return true;")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
+/// The C# type of the error a service constructor can fail with.
+fn ctor_error_type(ctor: &Function) -> String {
+    ctor.signature().rval().as_result().map(|x| field_to_type(x.e())).unwrap_or_default()
+}
+
+/// Writes the recommended dispose pattern (`Dispose()`, `Dispose(bool)` and a finalizer) calling the native destructor.
+pub fn write_pattern_service_dispose(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition) -> Result<(), Error> {
+    i.debug(w, "write_pattern_service_dispose")?;
+
+    let context_type_name = class.the_type().rust_name();
+    let dtor = function_name(class.destructor(), FunctionNameFlavor::RawFFIName);
+
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public void Dispose()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"Dispose(true);")?;
+    indented!(w, [()], r"GC.SuppressFinalize(this);")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    indented!(w, r"protected virtual void Dispose(bool disposing)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"if (_context == IntPtr.Zero) return;")?;
+    indented!(w, [()], r"var result = {}.{dtor}(_context);", i.class)?;
+    indented!(w, [()], r"_context = IntPtr.Zero;")?;
+    let events = class.events();
+    if !events.is_empty() {
+        for event in &events {
+            let name = event.name().to_upper_camel_case();
+            indented!(w, [()], r"{} on{name}Callback = null;", event.callback().name())?;
+        }
+        indented!(w, [()], r"lock (_eventLock)")?;
+        indented!(w, [()], r"{{")?;
+        for event in &events {
+            let name = event.name().to_upper_camel_case();
+            indented!(w, [()()], r"if (_on{name}Handle.IsAllocated)")?;
+            indented!(w, [()()], r"{{")?;
+            indented!(w, [()()()], r"on{name}Callback = ({})_on{name}Handle.Target;", event.callback().name())?;
+            indented!(w, [()()()], r"_on{name}Handle.Free();")?;
+            indented!(w, [()()], r"}}")?;
+            indented!(w, [()()], r"_on{name} = null;")?;
+        }
+        indented!(w, [()], r"}}")?;
+    }
+    indented!(w, [()], r"// Finalizers must not throw, so errors are only surfaced on explicit disposal.")?;
+    if events.is_empty() {
+        indented!(w, [()], r"if (disposing) result.AsOk();")?;
+    } else {
+        indented!(w, [()], r"if (!disposing) return;")?;
+        indented!(w, [()], r"result.AsOk();")?;
+        for event in &events {
+            let name = event.name().to_upper_camel_case();
+            indented!(w, [()], r"on{name}Callback?.Dispose();")?;
+        }
+    }
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    indented!(w, r"~{context_type_name}()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"Dispose(false);")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
+/// Writes a C# `event` for each register / unregister method pair of this service.
+pub fn write_pattern_service_events(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition) -> Result<(), Error> {
+    i.debug(w, "write_pattern_service_events")?;
+
+    let events = class.events();
+
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    indented!(w, r"private readonly object _eventLock = new object();")?;
+    w.newline()?;
+
+    for event in &events {
+        write_pattern_service_event(i, w, event)?;
+        w.newline()?;
+    }
+
+    Ok(())
+}
+
+/// Writes the event arguments, the backing fields and the `event` itself.
+///
+/// The native callback is registered on the first subscription and unregistered after the last handler
+/// detached. In between it is kept alive via a `GCHandle`, which is also released on disposal. If
+/// unregistering fails the handler is restored and the callback stays registered. Exceptions thrown
+/// by handlers can not cross into native code, they are rethrown once the callback is unregistered.
+pub fn write_pattern_service_event(i: &Interop, w: &mut IndentWriter, event: &ServiceEvent) -> Result<(), Error> {
+    i.debug(w, "write_pattern_service_event")?;
+
+    let name = event.name().to_upper_camel_case();
+    let args = format!("{name}EventArgs");
+    let callback = event.callback().name();
+    let register = function_name(event.register(), FunctionNameFlavor::RawFFIName);
+    let unregister = function_name(event.unregister(), FunctionNameFlavor::RawFFIName);
+    let register_check = if event.register().signature().rval().as_result().is_some() {
+        ".AsOk()"
+    } else {
+        ""
+    };
+    let unregister_check = if event.unregister().signature().rval().as_result().is_some() {
+        ".AsOk()"
+    } else {
+        ""
+    };
+
+    // The last callback parameter is the context pointer, which is not needed in C#.
+    let params = event.callback().fnpointer().signature().params();
+    let params = &params[..params.len().saturating_sub(1)];
+    let names = params.iter().map(Parameter::name).collect::<Vec<_>>().join(", ");
+
+    indented!(w, r"public class {args} : EventArgs")?;
+    indented!(w, r"{{")?;
+    for p in params {
+        indented!(w, [()], r"public readonly {} {};", field_to_type(p.the_type()), p.name())?;
+    }
+    w.newline()?;
+    let ctor_params = params
+        .iter()
+        .map(|x| format!("{} {}", field_to_type(x.the_type()), x.name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    indented!(w, [()], r"public {args}({ctor_params})")?;
+    indented!(w, [()], r"{{")?;
+    for p in params {
+        indented!(w, [()()], r"this.{0} = {0};", p.name())?;
+    }
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    indented!(w, r"private EventHandler<{args}> _on{name};")?;
+    indented!(w, r"private GCHandle _on{name}Handle;")?;
+    w.newline()?;
+
+    write_documentation(w, event.register().meta().docs())?;
+    indented!(w, r"public event EventHandler<{args}> On{name}")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"add")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"lock (_eventLock)")?;
+    indented!(w, [()()], r"{{")?;
+    indented!(w, [()()()], r"if (_on{name} == null)")?;
+    indented!(w, [()()()], r"{{")?;
+    indented!(w, [()()()()], r"var callback = new {callback}(({names}) => _on{name}?.Invoke(this, new {args}({names})));")?;
+    indented!(w, [()()()()], r"var handle = GCHandle.Alloc(callback);")?;
+    indented!(w, [()()()()], r"try {{ {}.{register}(_context, callback){register_check}; }}", i.class)?;
+    indented!(w, [()()()()], r"catch {{ handle.Free(); throw; }}")?;
+    indented!(w, [()()()()], r"_on{name}Handle = handle;")?;
+    indented!(w, [()()()], r"}}")?;
+    indented!(w, [()()()], r"_on{name} += value;")?;
+    indented!(w, [()()], r"}}")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, [()], r"remove")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"lock (_eventLock)")?;
+    indented!(w, [()()], r"{{")?;
+    indented!(w, [()()()], r"_on{name} -= value;")?;
+    indented!(w, [()()()], r"if (_on{name} != null || !_on{name}Handle.IsAllocated) return;")?;
+    indented!(w, [()()()], r"// Native code keeps calling the callback if unregistering fails, so it must stay alive.")?;
+    indented!(w, [()()()], r"try {{ {}.{unregister}(_context){unregister_check}; }}", i.class)?;
+    indented!(w, [()()()], r"catch {{ _on{name} += value; throw; }}")?;
+    indented!(w, [()()()], r"var callback = ({callback})_on{name}Handle.Target;")?;
+    indented!(w, [()()()], r"_on{name}Handle.Free();")?;
+    indented!(w, [()()()], r"// Rethrows what a handler raised while being invoked from native code.")?;
+    indented!(w, [()()()], r"callback.Dispose();")?;
+    indented!(w, [()()], r"}}")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
+/// Writes `ReadAllAsync()`, streaming the items of the service's channel as an `IAsyncEnumerable`.
+///
+/// The enumeration ends once the channel was closed. Cancelling a pending receive abandons it and
+/// disposes the service, releasing the native receiver. Consumers stopping early keep the service alive.
+pub fn write_pattern_service_channel(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition) -> Result<(), Error> {
+    i.debug(w, "write_pattern_service_channel")?;
+
+    let Some(channel) = class.channel() else {
+        return Ok(());
+    };
+
+    let item = field_to_type(channel.item());
+    let recv = function_name(channel.recv(), FunctionNameFlavor::CSharpMethodWithoutClass(&class.common_prefix()));
+    let option = channel
+        .recv()
+        .signature()
+        .params()
+        .get(1)
+        .and_then(|x| x.the_type().as_async_callback())
+        .and_then(|x| x.t().as_result())
+        .map(|x| field_to_type(x.t()))
+        .unwrap_or_default();
+
+    indented!(w, r"/// Receives items until the channel is closed. Cancelling disposes this service.")?;
+    indented!(w, r"public async IAsyncEnumerable<{item}> ReadAllAsync([EnumeratorCancellation] System.Threading.CancellationToken cancellationToken = default)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"while (true)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"{option} item;")?;
+    indented!(w, [()()], r"try {{ item = await {recv}().WaitAsync(cancellationToken); }}")?;
+    indented!(w, [()()], r"catch (OperationCanceledException) when (cancellationToken.IsCancellationRequested) {{ Dispose(); throw; }}")?;
+    indented!(w, [()()], r"if (item.IsNone) yield break;")?;
+    indented!(w, [()()], r"yield return item.AsSome();")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    Ok(())
+}
+
+/// Returns the native `<prefix>is_valid` function for this service, if the inventory declares one.
+///
+/// To qualify the function must accept a single pointer to the service's opaque type and return a `bool`.
+pub fn handle_validation_function<'a>(i: &'a Interop, class: &ServiceDefinition) -> Option<&'a Function> {
+    let name = format!("{}is_valid", class.common_prefix());
+    let is_handle = |x: &Type| x.pointer_target().and_then(Type::as_opaque_type) == Some(class.the_type());
+
+    i.inventory.functions().iter().find(|x| {
+        x.name() == name
+            && matches!(x.signature().params(), [p] if is_handle(p.the_type()))
+            && matches!(x.signature().rval(), Type::Primitive(Primitive::Bool) | Type::Pattern(TypePattern::Bool))
+    })
+}
+
+/// Writes `FromHandle` and `TryFromHandle` to wrap existing native handles.
+pub fn write_pattern_service_handles(i: &Interop, w: &mut IndentWriter, class: &ServiceDefinition) -> Result<(), Error> {
+    i.debug(w, "write_pattern_service_handles")?;
+
+    let context_type_name = class.the_type().rust_name();
+    let validation = handle_validation_function(i, class);
+
+    indented!(w, r"/// Wraps an existing native handle, taking ownership of it. The handle is not validated.")?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static {context_type_name} FromHandle(IntPtr handle)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"var self = new {context_type_name}();")?;
+    indented!(w, [()], r"self._context = handle;")?;
+    indented!(w, [()], r"return self;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    indented!(w, r"/// Wraps an existing native handle, taking ownership of it, if the handle is valid.")?;
+    match validation {
+        Some(f) => indented!(w, r"/// Handles are checked for null and validated via `{}`.", function_name(f, FunctionNameFlavor::RawFFIName))?,
+        None => indented!(w, r"/// Without a native `{}is_valid` function this can only check for null handles.", class.common_prefix())?,
+    }
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static bool TryFromHandle(IntPtr handle, out {context_type_name} service)")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"service = null;")?;
+    indented!(w, [()], r"if (handle == IntPtr.Zero) return false;")?;
+    if let Some(f) = validation {
+        let fn_name = function_name(f, FunctionNameFlavor::RawFFIName);
+        let is = if matches!(f.signature().rval(), Type::Pattern(TypePattern::Bool)) {
+            ".Is"
+        } else {
+            ""
+        };
+        indented!(w, [()], r"if (!{}.{fn_name}(handle){is}) return false;", i.class)?;
+    }
+    indented!(w, [()], r"service = FromHandle(handle);")?;
+    indented!(w, [()], r"return true;")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments, clippy::too_many_lines, clippy::cognitive_complexity)]
 pub fn write_pattern_service_method(
     i: &Interop,
@@ -74,7 +416,6 @@ pub fn write_pattern_service_method(
     let fn_name = match method_type {
         MethodType::Ctor => function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix)),
         MethodType::Regular => function_name(function, FunctionNameFlavor::CSharpMethodWithoutClass(&common_prefix)),
-        MethodType::Dtor => "Dispose".to_string(),
     };
 
     let mut static_prefix = "";
@@ -90,7 +431,6 @@ pub fn write_pattern_service_method(
                 Type::Pattern(TypePattern::Result(x)) => field_to_type(x.t()),
                 x => rval_to_type_sync(x),
             },
-            MethodType::Dtor => "void".to_string(),
         },
         SugaredReturnType::Async(Type::Pattern(TypePattern::Result(_))) => {
             names.pop();
@@ -117,7 +457,6 @@ pub fn write_pattern_service_method(
                 to_invoke.join(", ")
             }
         }
-        MethodType::Dtor => "_context".to_string(),
         MethodType::Regular => {
             if to_invoke.is_empty() {
                 "_context".to_string()
@@ -147,24 +486,26 @@ pub fn write_pattern_service_method(
 
     // Determine return value behavior and write function call.
     match async_rval {
-        SugaredReturnType::Sync(Type::Primitive(Primitive::Void)) => {
-            indented!(w, [()], r"{fn_call};",)?;
-        }
         _ if matches!(method_type, MethodType::Ctor) => {
-            indented!(w, [()], r"self._context = {fn_call}.AsOk();")?;
+            indented!(w, [()], r"var result = {fn_call};")?;
+            indented!(w, [()], r"if (!result.IsOk) throw new InteropException<{}>(result.AsErr());", ctor_error_type(function))?;
+            indented!(w, [()], r"self._context = result.AsOk();")?;
         }
-        _ if matches!(method_type, MethodType::Dtor) => {
-            indented!(w, [()], r"{fn_call}.AsOk();")?;
-            indented!(w, [()], r"_context = IntPtr.Zero;")?;
+        SugaredReturnType::Sync(Type::Primitive(Primitive::Void)) => {
+            indented!(w, [()], r"try {{ {fn_call}; }}")?;
+            write_keep_alive(w)?;
         }
         SugaredReturnType::Sync(Type::Pattern(TypePattern::Result(x))) if x.t().is_void() => {
-            indented!(w, [()], r"{fn_call}.AsOk();")?;
+            indented!(w, [()], r"try {{ {fn_call}.AsOk(); }}")?;
+            write_keep_alive(w)?;
         }
         SugaredReturnType::Sync(Type::Pattern(TypePattern::Result(x))) if !x.t().is_void() => {
-            indented!(w, [()], r"return {fn_call}.AsOk();")?;
+            indented!(w, [()], r"try {{ return {fn_call}.AsOk(); }}")?;
+            write_keep_alive(w)?;
         }
         _ => {
-            indented!(w, [()], r"return {fn_call};")?;
+            indented!(w, [()], r"try {{ return {fn_call}; }}")?;
+            write_keep_alive(w)?;
         }
     }
 
@@ -250,20 +591,24 @@ pub fn write_common_service_method_overload(i: &Interop, w: &mut IndentWriter, c
 
     match async_rval {
         SugaredReturnType::Sync(Type::Pattern(TypePattern::CStrPointer)) => {
-            indented!(w, [()], r"var s = {fn_call};")?;
-            indented!(w, [()], r"return Marshal.PtrToStringAnsi(s);")?;
+            indented!(w, [()], r"try {{ return Marshal.PtrToStringAnsi({fn_call}); }}")?;
+            write_keep_alive(w)?;
         }
         SugaredReturnType::Sync(Type::Primitive(Primitive::Void)) => {
-            indented!(w, [()], r"{fn_call};",)?;
+            indented!(w, [()], r"try {{ {fn_call}; }}")?;
+            write_keep_alive(w)?;
         }
         SugaredReturnType::Sync(Type::Pattern(TypePattern::Result(x))) if x.t().is_void() => {
-            indented!(w, [()], r"{fn_call}.AsOk();")?;
+            indented!(w, [()], r"try {{ {fn_call}.AsOk(); }}")?;
+            write_keep_alive(w)?;
         }
         SugaredReturnType::Sync(Type::Pattern(TypePattern::Result(x))) if !x.t().is_void() => {
-            indented!(w, [()], r"return {fn_call}.AsOk();")?;
+            indented!(w, [()], r"try {{ return {fn_call}.AsOk(); }}")?;
+            write_keep_alive(w)?;
         }
         _ => {
-            indented!(w, [()], r"return {fn_call};")?;
+            indented!(w, [()], r"try {{ return {fn_call}; }}")?;
+            write_keep_alive(w)?;
         }
     }
 
@@ -272,6 +617,13 @@ pub fn write_common_service_method_overload(i: &Interop, w: &mut IndentWriter, c
     Ok(())
 }
 
+/// Closes the `try` around a native call, so the finalizer cannot destroy `_context` while it is still in use.
+///
+/// Once `_context` was loaded the JIT may consider `this` dead for the rest of the call.
+fn write_keep_alive(w: &mut IndentWriter) -> Result<(), Error> {
+    indented!(w, [()], r"finally {{ GC.KeepAlive(this); }}")
+}
+
 pub fn params(function: &Function, method_type: MethodType, is_overload: bool) -> (Vec<String>, Vec<String>, Vec<String>) {
     let mut names = Vec::new();
     let mut to_invoke = Vec::new();
@@ -279,7 +631,6 @@ pub fn params(function: &Function, method_type: MethodType, is_overload: bool) -
 
     let skip_params = match method_type {
         MethodType::Ctor => 0,
-        MethodType::Dtor => 1,
         MethodType::Regular => 1,
     };
 
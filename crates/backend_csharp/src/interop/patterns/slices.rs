@@ -2,6 +2,8 @@ use crate::Interop;
 use crate::converter::{is_reusable, slice_t};
 use crate::utils::{MoveSemantics, write_common_marshaller};
 use interoptopus::backend::IndentWriter;
+use interoptopus::lang::{Primitive, Type};
+use interoptopus::pattern::TypePattern;
 use interoptopus::pattern::slice::SliceType;
 use interoptopus::{Error, indented};
 
@@ -90,6 +92,7 @@ pub fn write_pattern_fast_slice(i: &Interop, w: &mut IndentWriter, slice: &Slice
     i.inline_hint(w, 0)?;
     indented!(w, r"public static {name} From({the_type}[] managed)")?;
     indented!(w, r"{{")?;
+    write_pattern_slice_layout_check_call(w, slice)?;
     indented!(w, [()], r"var rval = new {name}();")?;
     indented!(w, [()], r"rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);")?;
     indented!(w, [()], r"rval._data = rval._handle.AddrOfPinnedObject();")?;
@@ -115,6 +118,7 @@ pub fn write_pattern_fast_slice(i: &Interop, w: &mut IndentWriter, slice: &Slice
     w.newline()?;
     write_pattern_slice_to_unmanaged(i, w)?;
     w.newline()?;
+    write_pattern_slice_layout_check(i, w, slice, &the_type)?;
     indented!(w, r"[CustomMarshaller(typeof({name}), MarshalMode.Default, typeof(Marshaller))]")?;
     indented!(w, r"private struct MarshallerMeta {{ }}")?;
     w.newline()?;
@@ -191,6 +195,7 @@ pub fn write_pattern_marshalling_slice(i: &Interop, w: &mut IndentWriter, slice:
     i.inline_hint(w, 0)?;
     indented!(w, r"public static unsafe {name} From({the_type}[] managed)")?;
     indented!(w, r"{{")?;
+    write_pattern_slice_layout_check_call(w, slice)?;
     indented!(w, [()], r"var rval = new {name}();")?;
     indented!(w, [()], r"var size = sizeof({marshaller_type}.Unmanaged);")?;
     indented!(w, [()], r"rval._data  = Marshal.AllocHGlobal(size * managed.Length);")?;
@@ -214,6 +219,7 @@ pub fn write_pattern_marshalling_slice(i: &Interop, w: &mut IndentWriter, slice:
     w.newline()?;
     write_pattern_slice_to_unmanaged(i, w)?;
     w.newline()?;
+    write_pattern_slice_layout_check(i, w, slice, &format!("{marshaller_type}.Unmanaged"))?;
     indented!(w, r"[CustomMarshaller(typeof({name}), MarshalMode.Default, typeof(Marshaller))]")?;
     indented!(w, r"private struct MarshallerMeta {{ }}")?;
     w.newline()?;
@@ -238,6 +244,68 @@ pub fn write_pattern_marshalling_slice(i: &Interop, w: &mut IndentWriter, slice:
     Ok(())
 }
 
+/// Returns the size and alignment Rust expects for elements of this slice, if it contains composites.
+///
+/// The values are those of the platform generating the bindings, so layouts depending on the pointer width are
+/// not checked as they would differ, e.g., in a 32 bit process.
+fn slice_element_size_align(slice: &SliceType) -> Option<(usize, usize)> {
+    match slice.t() {
+        Type::Composite(x) if !depends_on_pointer_width(slice.t()) => x.size_align(),
+        _ => None,
+    }
+}
+
+/// True if the layout of `t` contains pointers or pointer-sized integers.
+fn depends_on_pointer_width(t: &Type) -> bool {
+    match t {
+        Type::Primitive(x) => matches!(x, Primitive::Usize | Primitive::Isize),
+        Type::ReadPointer(_) | Type::ReadWritePointer(_) | Type::FnPointer(_) | Type::Opaque(_) => true,
+        Type::Array(x) => depends_on_pointer_width(x.the_type()),
+        Type::Composite(x) => x.fields().iter().any(|f| depends_on_pointer_width(f.the_type())),
+        Type::Enum(x) => x.variants().iter().filter_map(|v| v.kind().as_typed()).any(depends_on_pointer_width),
+        Type::Pattern(TypePattern::Bool | TypePattern::CChar | TypePattern::APIVersion | TypePattern::Bitflags(_)) => false,
+        Type::Pattern(x) => depends_on_pointer_width(&x.fallback_type()),
+    }
+}
+
+pub fn write_pattern_slice_layout_check_call(w: &mut IndentWriter, slice: &SliceType) -> Result<(), Error> {
+    if slice_element_size_align(slice).is_some() {
+        indented!(w, [()], r"AssertElementLayout();")?;
+    }
+
+    Ok(())
+}
+
+/// Writes a debug-only check that the native element type has the size and alignment Rust expects.
+pub fn write_pattern_slice_layout_check(i: &Interop, w: &mut IndentWriter, slice: &SliceType, native: &str) -> Result<(), Error> {
+    let Some((size, align)) = slice_element_size_align(slice) else {
+        return Ok(());
+    };
+
+    i.debug(w, "write_pattern_slice_layout_check")?;
+
+    let rust_name = slice_t(slice);
+
+    indented!(w, r#"[System.Diagnostics.Conditional("DEBUG")]"#)?;
+    indented!(w, r"static void AssertElementLayout()")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"var size = Unsafe.SizeOf<{native}>();")?;
+    indented!(w, [()], r"var align = (int) Marshal.OffsetOf<ElementAlignment>(nameof(ElementAlignment.element));")?;
+    indented!(w, [()], r#"System.Diagnostics.Debug.Assert(size == {size}, $"Size of '{rust_name}' is {{size}} bytes in C#, but {size} in Rust.");"#)?;
+    indented!(w, [()], r#"System.Diagnostics.Debug.Assert(align == {align}, $"Alignment of '{rust_name}' is {{align}} bytes in C#, but {align} in Rust.");"#)?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+    indented!(w, r"[StructLayout(LayoutKind.Sequential)]")?;
+    indented!(w, r"struct ElementAlignment")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"public byte padding;")?;
+    indented!(w, [()], r"public {native} element;")?;
+    indented!(w, r"}}")?;
+    w.newline()?;
+
+    Ok(())
+}
+
 pub fn write_pattern_slice_to_managed(i: &Interop, w: &mut IndentWriter, managed: &str) -> Result<(), Error> {
     i.inline_hint(w, 1)?;
     indented!(w, [()], r"internal unsafe {managed} ToManaged()")?;
@@ -5,6 +5,7 @@ pub mod fnptrs;
 
 use crate::Interop;
 use crate::interop::patterns::asynk::write_pattern_async_trampoline;
+use crate::interop::patterns::bitflags::write_pattern_bitflags;
 use crate::interop::patterns::callbacks::write_type_definition_named_callback;
 use crate::interop::patterns::slices::{SliceKind, write_pattern_slice};
 use crate::interop::patterns::vec::write_pattern_vec;
@@ -19,17 +20,25 @@ use interoptopus::pattern::TypePattern;
 
 pub fn write_type_definitions(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
     for the_type in i.inventory.ctypes() {
-        write_type_definition(i, w, the_type)?;
+        if i.should_emit_by_type(the_type) {
+            write_type_definition(i, w, the_type)?;
+        }
     }
 
     Ok(())
 }
 
-pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type) -> Result<(), Error> {
-    if !i.should_emit_by_type(the_type) {
-        return Ok(());
+pub fn write_builtin_type_definitions(i: &Interop, w: &mut IndentWriter) -> Result<(), Error> {
+    for the_type in i.inventory.ctypes() {
+        if i.should_emit_builtin_type(the_type) {
+            write_type_definition(i, w, the_type)?;
+        }
     }
 
+    Ok(())
+}
+
+pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type) -> Result<(), Error> {
     match the_type {
         Type::Primitive(_) => {}
         Type::Array(_) => {}
@@ -84,6 +93,10 @@ pub fn write_type_definition(i: &Interop, w: &mut IndentWriter, the_type: &Type)
             TypePattern::Utf8String(_) => {}
             TypePattern::CChar => {}
             TypePattern::APIVersion => {}
+            TypePattern::Bitflags(x) => {
+                write_pattern_bitflags(i, w, x)?;
+                w.newline()?;
+            }
             TypePattern::AsyncCallback(x) => {
                 write_pattern_async_trampoline(i, w, x)?;
                 w.newline()?;
@@ -1,18 +1,27 @@
-use crate::Interop;
 use crate::converter::{
-    field_as_unmanaged, field_name, field_to_managed, field_to_type, field_to_type_declaration_unmanaged, field_to_unmanaged, has_dispose, is_reusable,
+    field_as_unmanaged, field_name, field_to_managed, field_to_type, field_to_type_declaration_unmanaged, field_to_unmanaged, function_name, has_dispose, is_reusable,
+    param_to_type, rval_to_type_sync,
 };
 use crate::interop::docs::write_documentation;
 use crate::utils::{MoveSemantics, write_common_marshaller};
+use crate::{FunctionNameFlavor, Interop, Pooling};
 use interoptopus::backend::{IndentWriter, WriteFor};
-use interoptopus::lang::{Composite, Field, Layout, Type, Visibility};
+use interoptopus::lang::{Composite, Field, Function, Layout, Type, Visibility};
+use interoptopus::pattern::TypePattern;
 use interoptopus::{Error, indented};
 
 pub fn write_type_definition_composite(i: &Interop, w: &mut IndentWriter, the_type: &Composite) -> Result<(), Error> {
     i.debug(w, "write_type_definition_composite")?;
     write_documentation(w, the_type.meta().docs())?;
     write_type_definition_composite_body(i, w, the_type, WriteFor::Code)?;
-    write_type_definition_composite_marshaller(i, w, the_type)
+    write_type_definition_composite_marshaller(i, w, the_type)?;
+
+    if i.should_emit_pool(the_type) {
+        w.newline()?;
+        write_type_definition_composite_pool(i, w, the_type)?;
+    }
+
+    Ok(())
 }
 
 pub fn write_type_definition_composite_marshaller(i: &Interop, w: &mut IndentWriter, the_type: &Composite) -> Result<(), Error> {
@@ -25,7 +34,8 @@ pub fn write_type_definition_composite_marshaller(i: &Interop, w: &mut IndentWri
     } else {
         MoveSemantics::Move
     };
-    let idisposable = if has_dispose(&the_type.to_type()) { ": IDisposable" } else { "" };
+    let pooled = i.should_emit_pool(the_type);
+    let idisposable = if has_dispose(&the_type.to_type()) || pooled { ": IDisposable" } else { "" };
 
     indented!(w, r"[NativeMarshalling(typeof(MarshallerMeta))]")?;
     indented!(w, r"public partial {self_kind} {name} {idisposable}")?;
@@ -127,13 +137,32 @@ pub fn write_type_definition_composite_marshaller(i: &Interop, w: &mut IndentWri
                 continue;
             }
 
+            // Pooled instances might be returned before all fields were set.
             let name = field.name();
-            indented!(w, [()()], r"{name}.Dispose();")?;
+            let access = if pooled { "?." } else { "." };
+            indented!(w, [()()], r"{name}{access}Dispose();")?;
+        }
+        if pooled {
+            // Concurrent disposals must not return the same instance twice.
+            indented!(w, [()()], r"var pool = System.Threading.Interlocked.Exchange(ref _pool, null);")?;
+            indented!(w, [()()], r"pool?.Return(this);")?;
         }
         indented!(w, [()], r"}}")?;
     }
     w.newline()?;
 
+    if pooled {
+        indented!(w, [()], r"internal {name}Pool _pool;")?;
+        w.newline()?;
+        indented!(w, [()], r"internal void Reset()")?;
+        indented!(w, [()], r"{{")?;
+        for field in the_type.fields() {
+            indented!(w, [()()], r"{} = default;", field.name())?;
+        }
+        indented!(w, [()], r"}}")?;
+        w.newline()?;
+    }
+
     i.inline_hint(w, 1)?;
     indented!(w, [()], r"public override string ToString()")?;
     indented!(w, [()], r"{{")?;
@@ -153,6 +182,47 @@ pub fn write_type_definition_composite_marshaller(i: &Interop, w: &mut IndentWri
     Ok(())
 }
 
+/// Writes a `FooPool` renting out instances which return to the pool when disposed.
+pub fn write_type_definition_composite_pool(i: &Interop, w: &mut IndentWriter, the_type: &Composite) -> Result<(), Error> {
+    i.debug(w, "write_type_definition_composite_pool")?;
+
+    let name = the_type.rust_name();
+    let visibility = i.visibility_types.to_access_modifier();
+    let (collection, take, add) = match i.pooling {
+        Pooling::ThreadSafe => (format!("System.Collections.Concurrent.ConcurrentBag<{name}>"), "TryTake", "Add"),
+        Pooling::SingleThreaded | Pooling::Disabled => (format!("Stack<{name}>"), "TryPop", "Push"),
+    };
+
+    indented!(w, r"/// Pool of reusable `{name}` instances; disposing a rented instance returns it.")?;
+    indented!(w, r"{visibility} partial class {name}Pool")?;
+    indented!(w, r"{{")?;
+    indented!(w, [()], r"readonly {collection} _items = new();")?;
+    indented!(w, [()], r"readonly int _capacity;")?;
+    w.newline()?;
+    indented!(w, [()], r"public {name}Pool(int capacity = 64)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"_capacity = capacity;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"public {name} Rent()")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"if (!_items.{take}(out var item)) {{ item = new {name}(); }}")?;
+    indented!(w, [()()], r"item._pool = this;")?;
+    indented!(w, [()()], r"return item;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+    i.inline_hint(w, 1)?;
+    indented!(w, [()], r"internal void Return({name} item)")?;
+    indented!(w, [()], r"{{")?;
+    indented!(w, [()()], r"item.Reset();")?;
+    indented!(w, [()()], r"if (_items.Count < _capacity) {{ _items.{add}(item); }}")?;
+    indented!(w, [()], r"}}")?;
+    indented!(w, r"}}")?;
+
+    Ok(())
+}
+
 #[allow(clippy::unused_self)]
 pub fn write_type_definition_composite_layout_annotation(w: &mut IndentWriter, the_type: &Composite) -> Result<(), Error> {
     match the_type.repr().layout() {
@@ -201,6 +271,10 @@ pub fn write_type_definition_composite_body(i: &Interop, w: &mut IndentWriter, t
         w.newline()?;
     }
 
+    if write_for == WriteFor::Code {
+        write_type_definition_composite_operators(i, w, the_type)?;
+    }
+
     w.unindent();
     indented!(w, r"}}")?;
     w.newline()?;
@@ -208,6 +282,70 @@ pub fn write_type_definition_composite_body(i: &Interop, w: &mut IndentWriter, t
     Ok(())
 }
 
+/// Writes operator overloads for all functions declared via `#[ffi_function(operator_xxx_of = "...")]`.
+pub fn write_type_definition_composite_operators(i: &Interop, w: &mut IndentWriter, the_type: &Composite) -> Result<(), Error> {
+    let operators = i
+        .inventory
+        .functions()
+        .iter()
+        .filter(|f| f.operator().is_some_and(|(_, type_name)| type_name == the_type.rust_name()))
+        .filter(|f| has_operand_of(f, the_type));
+
+    for function in operators {
+        write_type_definition_composite_operator(i, w, function)?;
+        w.newline()?;
+    }
+
+    Ok(())
+}
+
+/// C# requires at least one operand of an operator to be the containing type (CS0563).
+///
+/// `#[ffi_function]` already rejects such operators, but inventories might also be assembled by hand.
+fn has_operand_of(function: &Function, the_type: &Composite) -> bool {
+    function.signature().params().iter().any(|p| {
+        let param_type = param_to_type(p.the_type());
+        param_type.strip_prefix("ref ").unwrap_or(&param_type) == the_type.rust_name()
+    })
+}
+
+pub fn write_type_definition_composite_operator(i: &Interop, w: &mut IndentWriter, function: &Function) -> Result<(), Error> {
+    i.debug(w, "write_type_definition_composite_operator")?;
+
+    let Some((operator, _)) = function.operator() else {
+        return Ok(());
+    };
+
+    let mut params = Vec::new();
+    let mut to_invoke = Vec::new();
+
+    // Operators can't have `ref` parameters, so we take them by value and pass a reference to our copy.
+    for p in function.signature().params() {
+        let name = p.name();
+        let the_type = param_to_type(p.the_type());
+
+        if let Some(the_type) = the_type.strip_prefix("ref ") {
+            params.push(format!("{the_type} {name}"));
+            to_invoke.push(format!("ref {name}"));
+        } else {
+            params.push(format!("{the_type} {name}"));
+            to_invoke.push(name.to_string());
+        }
+    }
+
+    let fn_name = function_name(function, FunctionNameFlavor::RawFFIName);
+    let fn_call = format!(r"{}.{}({})", i.class, fn_name, to_invoke.join(", "));
+
+    let (rval, body) = match function.signature().rval() {
+        Type::Pattern(TypePattern::Result(x)) => (field_to_type(x.t()), format!("{fn_call}.AsOk()")),
+        x => (rval_to_type_sync(x), fn_call),
+    };
+
+    write_documentation(w, function.meta().docs())?;
+    i.inline_hint(w, 0)?;
+    indented!(w, r"public static {rval} operator {}({}) => {body};", operator.symbol(), params.join(", "))
+}
+
 #[allow(clippy::single_match_else)]
 pub fn write_type_definition_composite_body_field(_: &Interop, w: &mut IndentWriter, field: &Field, _: &Composite) -> Result<(), Error> {
     let field_name = field_name(field);
@@ -316,6 +316,30 @@ pub fn write_type_definition_enum_variant_utils(i: &Interop, w: &mut IndentWrite
     indented!(w, [()], r"}}")?;
     w.newline()?;
 
+    if the_type.variants().iter().all(|v| matches!(v.kind(), VariantKind::Unit(_))) {
+        write_type_definition_enum_try_parse(i, w, the_type)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a `TryParse` matching the emitted variant names, for unit-only enums.
+pub fn write_type_definition_enum_try_parse(i: &Interop, w: &mut IndentWriter, the_type: &Enum) -> Result<(), Error> {
+    i.debug(w, "write_type_definition_enum_try_parse")?;
+    let name = the_type.rust_name();
+    let comparison = if i.enum_parse_ignore_case { "OrdinalIgnoreCase" } else { "Ordinal" };
+
+    indented!(w, [()], r"public static bool TryParse(string value, out {name} result)")?;
+    indented!(w, [()], r"{{")?;
+    for variant in the_type.variants() {
+        let vname = variant.name();
+        indented!(w, [()()], r#"if (string.Equals(value, "{vname}", StringComparison.{comparison})) {{ result = {vname}; return true; }}"#)?;
+    }
+    indented!(w, [()()], r"result = default;")?;
+    indented!(w, [()()], r"return false;")?;
+    indented!(w, [()], r"}}")?;
+    w.newline()?;
+
     Ok(())
 }
 
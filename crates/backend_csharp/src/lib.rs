@@ -123,6 +123,6 @@ pub(crate) mod interop;
 pub(crate) mod utils;
 
 pub use docs::{Markdown, MarkdownConfig};
-pub use interop::{FunctionNameFlavor, Interop, InteropBuilder, InteropBuilderError, Visibility, WriteTypes};
+pub use interop::{FunctionNameFlavor, Interop, InteropBuilder, InteropBuilderError, Pooling, Visibility, WriteTypes};
 
 interoptopus::codegen_template_engine!("**/*.cs");
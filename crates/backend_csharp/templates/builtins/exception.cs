@@ -4,3 +4,13 @@ public class InteropException : Exception
     {
     }
 }
+
+public class InteropException<T> : InteropException
+{
+    public T Error { get; private set; }
+
+    public InteropException(T error) : base()
+    {
+        Error = error;
+    }
+}
@@ -15,15 +15,30 @@ public partial class Utf8String : IDisposable
     {
         var rval = new Utf8String();
         var source = s.AsSpan();
-        Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-        var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
 
-        fixed (byte* p = utf8Bytes)
+        // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+        // The native side receives an explicit length, so no nul terminator is written.
+        var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+        byte[] rented = null;
+        Span<byte> utf8Bytes = maxLen <= {{stackalloc_threshold}}
+            ? stackalloc byte[maxLen]
+            : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+        try
+        {
+            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+            fixed (byte* p = utf8Bytes)
+            {
+                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                rval._ptr = native._ptr;
+                rval._len = native._len;
+                rval._capacity = native._capacity;
+            }
+        }
+        finally
         {
-            InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-            rval._ptr = native._ptr;
-            rval._len = native._len;
-            rval._capacity = native._capacity;
+            if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
         }
 
         return rval;
@@ -211,6 +211,7 @@ pub(crate) fn types_from_type_recursive(start: &Type, types: &mut HashSet<Type>)
             TypePattern::CChar => {}
             TypePattern::APIVersion => {}
             TypePattern::Utf8String(_) => {}
+            TypePattern::Bitflags(_) => {}
         },
     }
 }
@@ -261,6 +262,9 @@ pub(crate) fn extract_namespaces_from_types(types: &[Type], into: &mut HashSet<S
                 TypePattern::Vec(x) => {
                     into.insert(x.meta().module().to_string());
                 }
+                TypePattern::Bitflags(x) => {
+                    into.insert(x.meta().module().to_string());
+                }
             },
         }
     }
@@ -311,6 +315,7 @@ pub(crate) fn holds_opaque_without_ref(typ: &Type) -> bool {
             TypePattern::NamedCallback(_) => false,
             TypePattern::AsyncCallback(_) => false,
             TypePattern::Vec(x) => holds_opaque_without_ref(x.t()),
+            TypePattern::Bitflags(_) => false,
         },
     }
 }
@@ -439,6 +444,7 @@ pub fn is_global_type(t: &Type) -> bool {
             TypePattern::AsyncCallback(_) => false,
             TypePattern::Utf8String(_) => true,
             TypePattern::Vec(x) => is_global_type(x.t()),
+            TypePattern::Bitflags(_) => false,
         },
     }
 }
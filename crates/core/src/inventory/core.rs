@@ -231,6 +231,10 @@ impl InventoryBuilder {
                     LibraryPattern::Builtins(x) => {
                         self.functions.extend(x.functions().iter().cloned());
                     }
+                    LibraryPattern::Batch(x) => {
+                        self.functions.push(x.submit().clone());
+                        self.functions.extend(x.commands().iter().cloned());
+                    }
                 }
                 self.patterns.push(x);
             }
@@ -23,6 +23,7 @@ pub fn non_service_functions(inventory: &Inventory) -> Vec<&Function> {
                 service_methods.push(service.destructor().clone());
             }
             LibraryPattern::Builtins(_) => {}
+            LibraryPattern::Batch(_) => {}
         }
     }
 
@@ -1,4 +1,6 @@
 use crate::lang::{Docs, Meta, Primitive, Type, Visibility};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 
 /// How a struct is laid out in memory.
 #[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -41,6 +43,37 @@ impl Representation {
     }
 }
 
+/// The size and alignment of a type as seen by the host compiling the inventory.
+///
+/// These depend on the host platform, so they take no part in comparing or hashing types; otherwise,
+/// e.g., the [API guard](crate::pattern::api_guard) hash would differ between 32 and 64 bit hosts.
+#[derive(Clone, Copy, Debug)]
+struct HostLayout(Option<(usize, usize)>);
+
+impl PartialEq for HostLayout {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for HostLayout {}
+
+impl PartialOrd for HostLayout {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HostLayout {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for HostLayout {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
 /// Used for Rust and C `struct` with named fields, must be `#[repr(C)]`.
 ///
 /// Might translate to a struct or class in another language, equivalent on
@@ -61,6 +94,8 @@ pub struct Composite {
     fields: Vec<Field>,
     repr: Representation,
     meta: Meta,
+    size_align: HostLayout,
+    poolable: bool,
 }
 
 impl Composite {
@@ -73,13 +108,27 @@ impl Composite {
     /// Creates a new composite with the given name and type-level documentation.
     #[must_use]
     pub fn with_meta(name: String, fields: Vec<Field>, meta: Meta) -> Self {
-        Self { name, fields, meta, repr: Representation::default() }
+        Self { name, fields, meta, repr: Representation::default(), size_align: HostLayout(None), poolable: false }
     }
 
     /// Creates a new composite with the given name and type-level documentation.
     #[must_use]
     pub const fn with_meta_repr(name: String, fields: Vec<Field>, meta: Meta, repr: Representation) -> Self {
-        Self { name, fields, repr, meta }
+        Self { name, fields, repr, meta, size_align: HostLayout(None), poolable: false }
+    }
+
+    /// Records the size and alignment Rust uses for this type, so backends can verify their layout.
+    #[must_use]
+    pub const fn with_size_align(mut self, size: usize, align: usize) -> Self {
+        self.size_align = HostLayout(Some((size, align)));
+        self
+    }
+
+    /// Marks this type as frequently allocated, so backends may generate an object pool for it.
+    #[must_use]
+    pub const fn with_poolable(mut self, poolable: bool) -> Self {
+        self.poolable = poolable;
+        self
     }
 
     /// Gets the type's name.
@@ -109,6 +158,18 @@ impl Composite {
         &self.repr
     }
 
+    /// True if this type was marked via `#[ffi_type(poolable)]`.
+    #[must_use]
+    pub const fn is_poolable(&self) -> bool {
+        self.poolable
+    }
+
+    /// The size and alignment of this type in Rust, if known.
+    #[must_use]
+    pub const fn size_align(&self) -> Option<(usize, usize)> {
+        self.size_align.0
+    }
+
     #[must_use]
     pub fn to_type(&self) -> Type {
         Type::Composite(self.clone())
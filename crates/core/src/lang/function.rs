@@ -19,18 +19,57 @@ impl SugaredReturnType {
     }
 }
 
+/// An arithmetic operator a function can implement, e.g., via `#[ffi_function(operator_add_of = "Vec3")]`.
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl Operator {
+    /// The symbol of this operator, e.g., `+`.
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+        }
+    }
+}
+
 /// A named, exported `#[no_mangle] extern "C" fn f()` function.
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Function {
     name: String,
     meta: Meta,
     signature: Signature,
+    operator: Option<(Operator, String)>,
+    return_via_out: bool,
 }
 
 impl Function {
     #[must_use]
     pub const fn new(name: String, signature: Signature, meta: Meta) -> Self {
-        Self { name, meta, signature }
+        Self { name, meta, signature, operator: None, return_via_out: false }
+    }
+
+    /// Marks this function as implementing `operator` for the type with the given Rust name.
+    #[must_use]
+    pub fn with_operator(mut self, operator: Operator, type_name: String) -> Self {
+        self.operator = Some((operator, type_name));
+        self
+    }
+
+    /// Marks the last parameter of this function as an out-parameter holding its logical return value.
+    ///
+    /// This is set by `#[ffi_function(return_via_out)]`. The signature already reflects the native
+    /// contract, i.e., it returns nothing and ends with an `rval: *mut T` parameter.
+    #[must_use]
+    pub const fn with_return_via_out(mut self) -> Self {
+        self.return_via_out = true;
+        self
     }
 
     #[must_use]
@@ -48,6 +87,28 @@ impl Function {
         &self.meta
     }
 
+    /// The operator this function implements, and the Rust name of the type it belongs to.
+    #[must_use]
+    pub fn operator(&self) -> Option<(Operator, &str)> {
+        self.operator.as_ref().map(|(op, type_name)| (*op, type_name.as_str()))
+    }
+
+    /// If the logical return value is written to the last parameter instead of being returned.
+    #[must_use]
+    pub const fn returns_via_out(&self) -> bool {
+        self.return_via_out
+    }
+
+    /// The type written to the out-parameter, if this function [returns via out](Self::returns_via_out).
+    #[must_use]
+    pub fn out_return_type(&self) -> Option<&Type> {
+        if !self.return_via_out {
+            return None;
+        }
+
+        self.signature.params().last().and_then(|x| x.the_type().pointer_target())
+    }
+
     #[must_use]
     pub fn prettifier(&self) -> Prettifier {
         Prettifier::from_rust_lower(self.name())
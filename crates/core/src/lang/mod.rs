@@ -14,7 +14,7 @@ pub use composite::{Composite, Field, Layout, Opaque, Representation};
 pub use constant::{Constant, ConstantValue};
 pub use enums::{Enum, Variant, VariantKind};
 pub use fnpointer::FnPointer;
-pub use function::{Function, Parameter, Signature, SugaredReturnType};
+pub use function::{Function, Operator, Parameter, Signature, SugaredReturnType};
 pub use info::{ConstantInfo, FunctionInfo, TypeInfo};
 pub use meta::{Docs, Meta, Visibility};
 pub use primitive::{Primitive, PrimitiveValue};
@@ -109,6 +109,7 @@ impl Type {
             Self::ReadWritePointer(x) => format!("MutPtr{}", capitalize_first_letter(x.name_within_lib().as_str())),
             Self::Pattern(x) => match x {
                 TypePattern::Bool => "Bool".to_string(),
+                TypePattern::Bitflags(x) => x.rust_name().to_string(),
                 _ => x.fallback_type().name_within_lib(),
             },
             Self::Array(x) => x.rust_name(),
@@ -211,6 +212,7 @@ impl Type {
             Self::Opaque(t) => Some(t.meta().module()),
             Self::Composite(t) => Some(t.meta().module()),
             Self::Pattern(TypePattern::NamedCallback(t)) => Some(t.meta().module()),
+            Self::Pattern(TypePattern::Bitflags(t)) => Some(t.meta().module()),
             _ => None,
         }
     }
@@ -0,0 +1,191 @@
+//! Records many calls of small functions into one buffer, submitted in a single FFI call.
+//!
+//! For chatty APIs the per-call overhead can easily dominate the actual work. Functions
+//! annotated with `#[ffi_function(batchable)]` can instead be recorded into a command
+//! buffer by backends, and executed together by a submit function created via [`batch!`](crate::batch):
+//!
+//! ```
+//! use interoptopus::{batch, ffi_function};
+//!
+//! #[ffi_function(batchable)]
+//! pub fn set_pixel(x: u32, y: u32, color: u32) {}
+//!
+//! #[ffi_function(batchable)]
+//! pub fn clear(color: u32) {}
+//!
+//! // Defines `DrawBatch` and the native `submit_draw(commands: ffi::Slice<u8>) -> u32`.
+//! batch!(DrawBatch, submit_draw, [set_pixel, clear]);
+//! ```
+//!
+//! The batch then has to be registered via `.register(pattern!(DrawBatch))`. In C# this will
+//! produce a `DrawBatch` class with `SetPixel()`, `Clear()` and `SubmitBatch()` methods.
+//!
+//! # Wire Format
+//!
+//! The buffer handed to the submit function is a sequence of commands. Each command consists of
+//! a `u32` [`opcode`], a `u32` payload size and the payload itself, which holds the function's
+//! arguments laid out like a `#[repr(C)]` struct. All values use native byte order and no alignment
+//! is guaranteed. The submit function returns the number of commands executed, and stops at the
+//! first command with an unknown opcode or a mismatched payload size.
+//!
+//! Batchable functions must not return anything, and their parameters must implement [`AnyBitPattern`],
+//! since payloads are untrusted bytes recorded by a backend.
+use crate::lang::Function;
+use crate::pattern::primitive::{Bool, CChar};
+use std::collections::HashSet;
+
+/// Marks types for which every bit pattern of their size is a valid value.
+///
+/// Batchable functions can only accept such types, as their arguments are read from raw bytes.
+/// Implemented for primitives, [`Bool`], [`CChar`] and raw pointers.
+///
+/// # Safety
+///
+/// Implementors must be valid for any bit pattern, e.g., a `#[repr(C)]` struct without padding
+/// whose fields all implement this trait. Types like `bool`, `char`, enums or references must not
+/// implement it.
+pub unsafe trait AnyBitPattern: Copy {}
+
+macro_rules! impl_any_bit_pattern {
+    ($($t:ty),*) => {
+        $(unsafe impl AnyBitPattern for $t {})*
+    };
+}
+
+impl_any_bit_pattern!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, Bool, CChar);
+
+unsafe impl<T> AnyBitPattern for *const T {}
+unsafe impl<T> AnyBitPattern for *mut T {}
+
+/// Implemented by `#[ffi_function(batchable)]` for the function's helper struct.
+pub trait Batchable {
+    /// The opcode identifying this function in a command buffer.
+    const OPCODE: u32;
+
+    /// Decodes the arguments from `payload` and calls the function.
+    ///
+    /// Returns `false` if the payload size does not match the function's arguments.
+    fn invoke(payload: &[u8]) -> bool;
+}
+
+/// Computes the opcode of a batchable function from its exported name (32 bit FNV-1a).
+#[must_use]
+pub const fn opcode(name: &str) -> u32 {
+    let bytes = name.as_bytes();
+    let mut hash = 0x811c_9dc5_u32;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Splits `commands` into individual commands and passes opcode and payload to `dispatch`.
+///
+/// Returns the number of commands for which `dispatch` returned `true`, and stops at the first
+/// command it rejected or that was truncated.
+pub fn decode(commands: &[u8], mut dispatch: impl FnMut(u32, &[u8]) -> bool) -> u32 {
+    let mut rest = commands;
+    let mut executed = 0;
+
+    while let (Some(opcode), Some(size)) = (read_u32(rest, 0), read_u32(rest, 4)) {
+        let Some(payload) = rest.get(8..8 + size as usize) else {
+            break;
+        };
+
+        if !dispatch(opcode, payload) {
+            break;
+        }
+
+        rest = &rest[8 + payload.len()..];
+        executed += 1;
+    }
+
+    executed
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let bytes = bytes.get(offset..offset + 4)?;
+    Some(u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// A submit function and the batchable functions it can execute.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct BatchDefinition {
+    name: String,
+    submit: Function,
+    commands: Vec<Function>,
+}
+
+impl BatchDefinition {
+    /// Creates a new batch definition.
+    ///
+    /// # Panics
+    /// Panics if two commands share the same opcode.
+    #[must_use]
+    pub fn new(name: String, submit: Function, commands: Vec<Function>) -> Self {
+        let mut opcodes = HashSet::new();
+
+        for command in &commands {
+            assert!(opcodes.insert(opcode(command.name())), "Opcode of batchable function `{}` is not unique.", command.name());
+        }
+
+        Self { name, submit, commands }
+    }
+
+    /// The name of this batch, e.g., `DrawBatch`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The native function executing a buffer of commands.
+    #[must_use]
+    pub const fn submit(&self) -> &Function {
+        &self.submit
+    }
+
+    /// All functions that can be recorded into this batch.
+    #[must_use]
+    pub fn commands(&self) -> &[Function] {
+        &self.commands
+    }
+}
+
+/// Defines a batch and its submit function for the given `#[ffi_function(batchable)]` functions.
+///
+/// See the [module level documentation](crate::pattern::batch) for details.
+#[macro_export]
+macro_rules! batch {
+    ($name:ident, $submit:ident, [$($command:ident),* $(,)?]) => {
+        #[::interoptopus::ffi_function]
+        pub fn $submit(commands: ::interoptopus::ffi::Slice<u8>) -> u32 {
+            $crate::pattern::batch::decode(commands.as_slice(), |opcode, payload| {
+                $(
+                    if opcode == <$command as $crate::pattern::batch::Batchable>::OPCODE {
+                        return <$command as $crate::pattern::batch::Batchable>::invoke(payload);
+                    }
+                )*
+                false
+            })
+        }
+
+        pub struct $name;
+
+        impl $crate::pattern::LibraryPatternInfo for $name {
+            fn pattern_info() -> $crate::pattern::LibraryPattern {
+                use $crate::lang::FunctionInfo;
+
+                let submit = <$submit>::function_info();
+                let commands = ::std::vec![$(<$command>::function_info()),*];
+                let batch = $crate::pattern::batch::BatchDefinition::new(stringify!($name).to_string(), submit, commands);
+
+                $crate::pattern::LibraryPattern::Batch(batch)
+            }
+        }
+    };
+}
@@ -0,0 +1,88 @@
+//! Flag sets created by the `bitflags!` macro, becoming `[Flags]` enums in C#.
+//!
+//! Annotate the macro invocation with `#[ffi_type]`; the struct will be made `#[repr(transparent)]`
+//! and all named flag constants are exported:
+//!
+//! ```ignore
+//! use bitflags::bitflags;
+//! use interoptopus::ffi_type;
+//!
+//! #[ffi_type]
+//! bitflags! {
+//!     #[derive(Copy, Clone)]
+//!     pub struct Permissions: u32 {
+//!         const READ = 0b001;
+//!         const WRITE = 0b010;
+//!         const READ_WRITE = Self::READ.bits() | Self::WRITE.bits();
+//!     }
+//! }
+//! ```
+//!
+//! Backends without support for this pattern use the underlying integer instead.
+use crate::lang::{Meta, Primitive, Type};
+
+/// A named flag of a [`BitflagsType`].
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Flag {
+    name: String,
+    value: u64,
+}
+
+impl Flag {
+    #[must_use]
+    pub const fn new(name: String, value: u64) -> Self {
+        Self { name, value }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+/// A `bitflags!` struct wrapping a primitive integer.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct BitflagsType {
+    name: String,
+    bits: Primitive,
+    flags: Vec<Flag>,
+    meta: Meta,
+}
+
+impl BitflagsType {
+    #[must_use]
+    pub const fn new(name: String, bits: Primitive, flags: Vec<Flag>, meta: Meta) -> Self {
+        Self { name, bits, flags, meta }
+    }
+
+    #[must_use]
+    pub fn rust_name(&self) -> &str {
+        &self.name
+    }
+
+    /// The integer type holding the flags.
+    #[must_use]
+    pub const fn bits(&self) -> Primitive {
+        self.bits
+    }
+
+    #[must_use]
+    pub fn flags(&self) -> &[Flag] {
+        &self.flags
+    }
+
+    #[must_use]
+    pub const fn meta(&self) -> &Meta {
+        &self.meta
+    }
+
+    #[must_use]
+    pub const fn fallback_type(&self) -> Type {
+        Type::Primitive(self.bits)
+    }
+}
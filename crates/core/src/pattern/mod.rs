@@ -69,6 +69,8 @@
 //! not in all backends. For example, something like `Slice<Result<Option<String>, Error>>` is supported in
 //! Rust without issues, but its UX might suffer in Python.
 use crate::lang::{Composite, Primitive, Type, TypeInfo};
+use crate::pattern::batch::BatchDefinition;
+use crate::pattern::bitflags::BitflagsType;
 use crate::pattern::builtins::Builtins;
 use crate::pattern::callback::{AsyncCallback, NamedCallback};
 use crate::pattern::option::OptionType;
@@ -82,6 +84,8 @@ use std::ffi::c_char;
 pub mod api_entry;
 pub mod api_guard;
 pub mod asynk;
+pub mod batch;
+pub mod bitflags;
 pub mod builtins;
 pub mod callback;
 pub mod cstr;
@@ -101,6 +105,7 @@ pub mod vec;
 pub enum LibraryPattern {
     Service(ServiceDefinition),
     Builtins(Builtins),
+    Batch(BatchDefinition),
 }
 
 /// Used mostly internally and provides pattern info for auto generated structs.
@@ -131,6 +136,7 @@ pub enum TypePattern {
     NamedCallback(NamedCallback),
     AsyncCallback(AsyncCallback),
     Vec(VecType),
+    Bitflags(BitflagsType),
 }
 
 impl TypePattern {
@@ -153,6 +159,7 @@ impl TypePattern {
             Self::AsyncCallback(x) => Type::FnPointer(x.fnpointer().clone()),
             Self::Utf8String(x) => Type::Composite(x.clone()),
             Self::Vec(x) => Type::Composite(x.composite_type().clone()),
+            Self::Bitflags(x) => x.fallback_type(),
         }
     }
 }
@@ -74,6 +74,30 @@
 //! x.JustReturnValue();
 //! x.Dispose();
 //! ```
+//!
+//! Constructors throw an `InteropException<Error>` carrying the returned error if the native call
+//! fails; each also has a non-throwing variant, e.g., `SimpleService.TryNewWith(123, out var x)`,
+//! or `SimpleService.TryNewWith(123, out var x, out var error)` to inspect the error.
+//!
+//! Existing native handles can be wrapped via `SimpleService.FromHandle(ptr)`, or checked first
+//! via `SimpleService.TryFromHandle(ptr, out var x)`. If the inventory contains a
+//! `simple_service_is_valid(ptr) -> bool` function it is used to validate the handle, otherwise
+//! `TryFromHandle` can only check for null.
+//!
+//! A method pair `register_tick(&mut self, callback: TickCallback)` and `unregister_tick(&mut self)`,
+//! where `TickCallback` is a [`callback!`](crate::callback) returning nothing, forms a [`ServiceEvent`].
+//! In C# it becomes an `event EventHandler<TickEventArgs> OnTick`, registering the callback on the
+//! first subscription and unregistering it once the last handler detaches.
+//!
+//! An async method `recv_async(this: AsyncSelf<Self>) -> ffi::Result<ffi::Option<T>, _>` marks the
+//! service as the receiving end of a channel, see [`ServiceChannel`]. In C# the service then also
+//! offers `ReadAllAsync()`, an `IAsyncEnumerable<T>` ending once `recv_async` returns `None`.
+//!
+//! Service classes implement the full dispose pattern. If an instance is never disposed its
+//! finalizer still releases the native handle, but destructor errors are then ignored. Note that
+//! the finalizer runs on the runtime's finalizer thread, so the native destructor of undisposed
+//! services, including handles wrapped via `FromHandle`, is then called from that thread. Services
+//! which must be destroyed on a particular thread have to be disposed explicitly.
 
 //!
 //! In other languages and on the C FFI level the following methods would be emitted:
@@ -87,7 +111,9 @@
 //!
 
 use crate::backend::longest_common_prefix;
-use crate::lang::{Function, Opaque};
+use crate::lang::{Function, Opaque, Type};
+use crate::pattern::TypePattern;
+use crate::pattern::callback::NamedCallback;
 use crate::pattern::result::ResultAsPtr;
 use std::fmt::Debug;
 use std::slice::from_ref;
@@ -161,6 +187,110 @@ impl ServiceDefinition {
         all_methods.extend_from_slice(from_ref(&self.destructor));
         longest_common_prefix(all_methods.as_slice())
     }
+
+    /// Returns all events formed by `register_x` and `unregister_x` method pairs.
+    #[must_use]
+    pub fn events(&self) -> Vec<ServiceEvent> {
+        let prefix = self.common_prefix();
+        let mut rval = Vec::new();
+
+        for register in &self.methods {
+            let Some(name) = register.name().strip_prefix(&prefix).and_then(|x| x.strip_prefix("register_")) else {
+                continue;
+            };
+
+            let callback = match register.signature().params() {
+                [_, x] => match x.the_type() {
+                    Type::Pattern(TypePattern::NamedCallback(x)) if x.fnpointer().signature().rval().is_void() => x,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            let unregister_name = format!("{prefix}unregister_{name}");
+            let Some(unregister) = self.methods.iter().find(|x| x.name() == unregister_name && x.signature().params().len() == 1) else {
+                continue;
+            };
+
+            rval.push(ServiceEvent { name: name.to_string(), register: register.clone(), unregister: unregister.clone(), callback: callback.clone() });
+        }
+
+        rval
+    }
+
+    /// Returns the channel this service receives from, if it has a `recv_async` method.
+    ///
+    /// To qualify the method must be async and return an `ffi::Result<ffi::Option<T>, _>`,
+    /// where `None` signals the channel was closed.
+    #[must_use]
+    pub fn channel(&self) -> Option<ServiceChannel> {
+        let name = format!("{}recv_async", self.common_prefix());
+        let recv = self.methods.iter().find(|x| x.name() == name)?;
+
+        let item = match recv.signature().params() {
+            [_, x] => match x.the_type().as_async_callback()?.t().as_result()?.t() {
+                Type::Pattern(TypePattern::Option(x)) => x.t(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(ServiceChannel { recv: recv.clone(), item: item.clone() })
+    }
+}
+
+/// The receiving end of a channel exposed by a [`ServiceDefinition`], e.g., to stream results to the caller.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ServiceChannel {
+    recv: Function,
+    item: Type,
+}
+
+impl ServiceChannel {
+    /// The async method returning the next item, or `None` once the channel was closed.
+    #[must_use]
+    pub const fn recv(&self) -> &Function {
+        &self.recv
+    }
+
+    /// The type of the items received.
+    #[must_use]
+    pub const fn item(&self) -> &Type {
+        &self.item
+    }
+}
+
+/// A callback a [`ServiceDefinition`] can register and unregister, e.g., to notify about state changes.
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ServiceEvent {
+    name: String,
+    register: Function,
+    unregister: Function,
+    callback: NamedCallback,
+}
+
+impl ServiceEvent {
+    /// The name of this event, e.g., `tick` for `register_tick`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn register(&self) -> &Function {
+        &self.register
+    }
+
+    #[must_use]
+    pub const fn unregister(&self) -> &Function {
+        &self.unregister
+    }
+
+    /// The callback invoked when this event fires.
+    #[must_use]
+    pub const fn callback(&self) -> &NamedCallback {
+        &self.callback
+    }
 }
 
 pub trait ServiceInfo {
@@ -1,10 +1,10 @@
 use crate::functions::Attributes;
 use crate::util::{extract_doc_lines, purge_lifetimes_from_type};
 use proc_macro2::TokenStream;
-use quote::{ToTokens, quote, quote_spanned};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use syn::spanned::Spanned;
-use syn::{FnArg, GenericParam, ItemFn, Pat, ReturnType, Signature, Type};
+use syn::{FnArg, GenericArgument, GenericParam, ItemFn, Pat, PathArguments, ReturnType, Signature, Type};
 
 pub fn fn_signature_type(signature: &Signature) -> TokenStream {
     let rval = &signature.output;
@@ -73,8 +73,8 @@ pub fn ffi_function_freestanding(ffi_attributes: &Attributes, input: TokenStream
     let mut generic_parameters = Vec::new();
     let mut generic_ident = Vec::new();
 
-    let signature = fn_signature_type(&item_fn.sig);
-    let rval = rval_tokens(&item_fn.sig.output);
+    let mut signature = fn_signature_type(&item_fn.sig);
+    let mut rval = rval_tokens(&item_fn.sig.output);
 
     for generic in &item_fn.sig.generics.params {
         match generic {
@@ -146,19 +146,136 @@ pub fn ffi_function_freestanding(ffi_attributes: &Attributes, input: TokenStream
         }
     }
 
-    // Ensure we have the right attributes
-    if item_fn.sig.abi.is_none() {
-        item_fn.sig.abi = Some(syn::parse_quote!(extern "C"));
-    }
+    let mut return_via_out = quote! {};
 
-    if !item_fn.attrs.iter().any(|attr| attr.path().is_ident("no_mangle")) {
-        item_fn.attrs.push(syn::parse_quote!(#[unsafe(no_mangle)]));
-    }
+    let exported_fn = if ffi_attributes.return_via_out {
+        let ReturnType::Type(_, rval_type) = &item_fn.sig.output else {
+            panic!("Functions returning via out must return a value.")
+        };
+
+        assert!(!args_name.iter().any(|x| x == "rval"), "Functions returning via out must not have a parameter named `rval`.");
+
+        let input_types = item_fn
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|x| if let FnArg::Typed(x) = x { Some(x.ty.as_ref()) } else { None })
+            .collect::<Vec<_>>();
+        let input_names = (0..input_types.len()).map(|x| format_ident!("x{x}")).collect::<Vec<_>>();
+        let generics = &item_fn.sig.generics;
+        let span = item_fn.sig.span();
+
+        args_name.push("rval".to_string());
+        args_type.push(quote! { <*mut #rval_type as ::interoptopus::lang::TypeInfo>::type_info() });
+        signature = quote_spanned!(span=> extern "C" fn(#(#input_types,)* *mut #rval_type));
+        rval = quote! { ::interoptopus::lang::Type::Primitive(::interoptopus::lang::Primitive::Void) };
+        return_via_out = quote! { .with_return_via_out() };
+
+        // The annotated function stays a regular Rust function, the exported symbol writes its result to `rval` instead.
+        quote! {
+            #item_fn
+
+            const _: () = {
+                #[unsafe(export_name = #export_name)]
+                #[allow(unused_unsafe)]
+                extern "C" fn __interoptopus_return_via_out #generics (#(#input_names: #input_types,)* rval: *mut #rval_type) {
+                    let result = unsafe { #function_ident(#(#input_names),*) };
+
+                    if !rval.is_null() {
+                        unsafe { rval.write(result) };
+                    }
+                }
+            };
+        }
+    } else {
+        // Ensure we have the right attributes
+        if item_fn.sig.abi.is_none() {
+            item_fn.sig.abi = Some(syn::parse_quote!(extern "C"));
+        }
+
+        if !item_fn.attrs.iter().any(|attr| attr.path().is_ident("no_mangle")) {
+            item_fn.attrs.push(syn::parse_quote!(#[unsafe(no_mangle)]));
+        }
 
-    item_fn.attrs.push(syn::parse_quote!(#[unsafe(export_name = #export_name)]));
+        item_fn.attrs.push(syn::parse_quote!(#[unsafe(export_name = #export_name)]));
+
+        quote! { #item_fn }
+    };
+
+    let operator = match ffi_attributes.operator() {
+        Some((op, type_name)) => {
+            assert!(!ffi_attributes.return_via_out, "Operator functions can not return via out.");
+            assert_eq!(args_name.len(), 2, "Operator functions must have exactly two parameters.");
+
+            if !returns_value(&item_fn.sig.output) {
+                return syn::Error::new_spanned(&item_fn.sig.output, "Operator functions must return a value.").to_compile_error();
+            }
+
+            let has_operand = item_fn.sig.inputs.iter().any(|x| matches!(x, FnArg::Typed(x) if is_operand_of(&x.ty, type_name)));
+            if !has_operand {
+                let message = format!("Operator functions of `{type_name}` must take at least one `{type_name}` parameter (by value or reference).");
+                return syn::Error::new_spanned(&item_fn.sig.inputs, message).to_compile_error();
+            }
+
+            let op = syn::Ident::new(op, item_fn.sig.span());
+            quote! { .with_operator(::interoptopus::lang::Operator::#op, #type_name.to_string()) }
+        }
+        None => quote! {},
+    };
+
+    let batchable = if ffi_attributes.batchable {
+        assert!(matches!(item_fn.sig.output, ReturnType::Default), "Batchable functions must not return a value.");
+        assert!(!ffi_attributes.return_via_out, "Batchable functions can not return via out.");
+        assert!(generic_parameters.is_empty(), "Batchable functions can not have lifetimes.");
+
+        let input_types = item_fn
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|x| if let FnArg::Typed(x) = x { Some(x.ty.as_ref()) } else { None })
+            .collect::<Vec<_>>();
+        let input_names = (0..input_types.len()).map(|x| format_ident!("x{x}")).collect::<Vec<_>>();
+
+        for ty in &input_types {
+            assert!(matches!(ty, Type::Path(_) | Type::Ptr(_)), "Batchable functions can only accept plain values.");
+        }
+
+        let any_bit_pattern = input_types.iter().map(|ty| {
+            quote_spanned! { ty.span() => assert_any_bit_pattern::<#ty>(); }
+        });
+
+        // Arguments are recorded by backends as if they were fields of a `#[repr(C)]` struct.
+        quote! {
+            impl ::interoptopus::pattern::batch::Batchable for #function_ident {
+                const OPCODE: u32 = ::interoptopus::pattern::batch::opcode(#export_name);
+
+                #[allow(unused_unsafe)]
+                fn invoke(payload: &[u8]) -> bool {
+                    #[repr(C)]
+                    struct Args { #(#input_names: #input_types,)* }
+
+                    // Payloads are untrusted, so reading them is only sound if any bit pattern is valid.
+                    const fn assert_any_bit_pattern<T: ::interoptopus::pattern::batch::AnyBitPattern>() {}
+                    #(#any_bit_pattern)*
+
+                    if payload.len() != ::std::mem::size_of::<Args>() {
+                        return false;
+                    }
+
+                    let args = unsafe { ::std::ptr::read_unaligned(payload.as_ptr().cast::<Args>()) };
+                    unsafe { #function_ident(#(args.#input_names),*) };
+                    true
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let rval = quote! {
-        #item_fn
+        #exported_fn
+
+        #batchable
 
         #[allow(non_camel_case_types)]
         #[allow(clippy::redundant_pub_crate, clippy::forget_non_drop)]
@@ -184,10 +301,40 @@ pub fn ffi_function_freestanding(ffi_attributes: &Attributes, input: TokenStream
                 let docs = ::interoptopus::lang::Docs::from_lines(doc_lines);
                 let meta = ::interoptopus::lang::Meta::with_docs(docs);
 
-                ::interoptopus::lang::Function::new(#export_name.to_string(), sig, meta)
+                ::interoptopus::lang::Function::new(#export_name.to_string(), sig, meta) #operator #return_via_out
             }
         }
     };
 
     rval
 }
+
+/// True if `output` produces a value, i.e., is neither `()` nor `Result<(), E>`.
+fn returns_value(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+
+    match ty.as_ref() {
+        Type::Tuple(x) => !x.elems.is_empty(),
+        Type::Path(x) => match x.path.segments.last() {
+            Some(segment) if segment.ident == "Result" => match &segment.arguments {
+                PathArguments::AngleBracketed(args) => !matches!(args.args.first(), Some(GenericArgument::Type(Type::Tuple(x))) if x.elems.is_empty()),
+                _ => true,
+            },
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+/// True if `ty` is the type named `type_name`, or a reference to it.
+fn is_operand_of(ty: &Type, type_name: &str) -> bool {
+    match ty {
+        Type::Reference(x) => is_operand_of(&x.elem, type_name),
+        Type::Group(x) => is_operand_of(&x.elem, type_name),
+        Type::Paren(x) => is_operand_of(&x.elem, type_name),
+        Type::Path(x) => x.path.segments.last().is_some_and(|x| x.ident == type_name && x.arguments.is_empty()),
+        _ => false,
+    }
+}
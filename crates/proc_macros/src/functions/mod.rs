@@ -6,6 +6,7 @@ use proc_macro2::TokenStream;
 mod freestanding;
 
 #[derive(Debug, FromMeta)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Attributes {
     #[darling(default)]
     debug: bool,
@@ -15,6 +16,37 @@ pub struct Attributes {
 
     #[darling(default)]
     export_as: String,
+
+    #[darling(default)]
+    return_via_out: bool,
+
+    #[darling(default)]
+    batchable: bool,
+
+    #[darling(default)]
+    operator_add_of: String,
+
+    #[darling(default)]
+    operator_sub_of: String,
+
+    #[darling(default)]
+    operator_mul_of: String,
+}
+
+impl Attributes {
+    /// Returns the operator variant and type name if this function backs an operator.
+    ///
+    /// # Panics
+    /// Panics if more than one operator was specified.
+    pub fn operator(&self) -> Option<(&'static str, &str)> {
+        let operators = [("Add", &self.operator_add_of), ("Sub", &self.operator_sub_of), ("Mul", &self.operator_mul_of)];
+        let mut declared = operators.into_iter().filter(|(_, type_name)| !type_name.is_empty());
+        let rval = declared.next().map(|(op, type_name)| (op, type_name.as_str()));
+
+        assert!(declared.next().is_none(), "A function can only implement a single operator.");
+
+        rval
+    }
 }
 
 pub fn ffi_function(attr: TokenStream, input: TokenStream) -> TokenStream {
@@ -23,12 +23,13 @@ use proc_macro::TokenStream;
 ///
 /// | Attribute | On |  Explanation |
 /// | --- | --- | ---  |
-/// | `name="X"` | `struct`,`enum` | Uses `name` as the base interop name instead of the item's Rust name.<sup>1</sup> |
-/// | `namespace="X"` | `struct`,`enum` | Determine which namespace or file item should go. <sup>2</sup>
+/// | `name="X"` | `struct`,`enum`,`bitflags!` | Uses `name` as the base interop name instead of the item's Rust name.<sup>1</sup> |
+/// | `namespace="X"` | `struct`,`enum`,`bitflags!` | Determine which namespace or file item should go. <sup>2</sup>
 /// | `skip(x)` | `struct,enum` | Skip field or variant `x` in the definition, e.g., some `x` of [`PhantomData`](std::marker::PhantomData). <sup>⚠️</sup>
 /// | `opaque` | `struct` | Creates an opaque type without fields. Can only be used behind a pointer. <sup>3</sup> |
 /// | `transparent` | `struct, enum` | The struct or single variant enum will be `#[repr(transparent)]`. <sup>3</sup> |
 /// | `packed` | `struct` | The struct will be `#[repr(packed)]`. <sup>3</sup> |
+/// | `poolable` | `struct` | Backends may generate an object pool for this type, e.g., a C# `FooPool`. |
 /// | `error` | `enum` | The enum will follow the `FFIError` result pattern. |
 /// | `u8`, ..., `u64` | `enum` | Creates an opaque type without fields. Can only be used behind a pointer. |
 /// | `visibility(x="v")` | `struct` | Override visibility for field `x` as `public` or `private`; `_all` means all fields. <sup>2</sup>
@@ -60,6 +61,7 @@ use proc_macro::TokenStream;
 /// | Pattern | On |  Explanation |
 /// | --- | --- | ---  |
 /// | `ffi_error` | `enum` | Denotes this as a [`FFIError`](https://docs.rs/interoptopus/latest/interoptopus/patterns/result/trait.FFIError.html). |
+/// | _implicit_ | `bitflags!` | Exports the flag set as a [`BitflagsType`](https://docs.rs/interoptopus/latest/interoptopus/pattern/bitflags/struct.BitflagsType.html), e.g., a C# `[Flags]` enum. |
 ///
 /// # Examples
 ///
@@ -96,6 +98,18 @@ pub fn ffi_type(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// | Parameter |  Explanation |
 /// | --- | ---  |
 /// | `debug` | Print generated helper code in console.
+/// | `operator_add_of = "T"` | Use this function as `+` operator of composite `T` in languages supporting it.
+/// | `operator_sub_of = "T"` | Use this function as `-` operator of composite `T` in languages supporting it.
+/// | `operator_mul_of = "T"` | Use this function as `*` operator of composite `T` in languages supporting it.
+/// | `batchable` | Allow the function to be recorded into a [`batch`](https://docs.rs/interoptopus/latest/interoptopus/pattern/batch/index.html) and submitted with others in a single call. Parameters must implement `AnyBitPattern`.
+/// | `return_via_out` | Export the function as returning nothing, writing its result to an extra last parameter `rval: *mut T` instead. <sup>⚠️</sup>
+///
+/// <sup>⚠️</sup> This changes the native contract of the exported symbol, so all bindings must be regenerated. It avoids
+/// relying on how large structs are returned by value (e.g., via a hidden pointer), which is a frequent source of ABI mismatches. The
+/// annotated function itself keeps its signature and remains callable from Rust.
+///
+/// Operator functions must take at least one `T` by value or reference, since C# requires an operand to be of the
+/// containing type, and must return a value other than `()` or `ffi::Result<(), E>`.
 ///
 /// # Safety
 ///
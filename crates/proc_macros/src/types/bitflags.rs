@@ -0,0 +1,133 @@
+use crate::types::Attributes;
+use crate::util::extract_doc_lines;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Expr, Ident, ItemMacro, Token, Visibility, braced};
+
+// Mirrors the struct form accepted by `bitflags!`, e.g.,
+//
+// ```
+// bitflags! {
+//     #[derive(Copy, Clone)]
+//     pub struct Permissions: u32 {
+//         const READ = 0b001;
+//         const WRITE = 0b010;
+//         const _ = !0;
+//     }
+// }
+// ```
+struct BitflagsStruct {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    bits: Ident,
+    flags: Vec<BitflagsConst>,
+}
+
+struct BitflagsConst {
+    attrs: Vec<Attribute>,
+    ident: Ident,
+    expr: Expr,
+}
+
+impl Parse for BitflagsStruct {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let bits = input.parse()?;
+
+        let content;
+        braced!(content in input);
+
+        let mut flags = Vec::new();
+
+        while !content.is_empty() {
+            let attrs = content.call(Attribute::parse_outer)?;
+            content.parse::<Token![const]>()?;
+            let ident = if content.peek(Token![_]) {
+                let underscore = content.parse::<Token![_]>()?;
+                Ident::new("_", underscore.span)
+            } else {
+                content.parse()?
+            };
+            content.parse::<Token![=]>()?;
+            let expr = content.parse()?;
+            content.parse::<Token![;]>()?;
+            flags.push(BitflagsConst { attrs, ident, expr });
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("Only a single struct per `bitflags!` invocation is supported."));
+        }
+
+        Ok(Self { attrs, vis, ident, bits, flags })
+    }
+}
+
+fn bits_primitive(bits: &Ident) -> TokenStream {
+    match bits.to_string().as_str() {
+        "u8" => quote! { ::interoptopus::lang::Primitive::U8 },
+        "u16" => quote! { ::interoptopus::lang::Primitive::U16 },
+        "u32" => quote! { ::interoptopus::lang::Primitive::U32 },
+        "u64" => quote! { ::interoptopus::lang::Primitive::U64 },
+        "i8" => quote! { ::interoptopus::lang::Primitive::I8 },
+        "i16" => quote! { ::interoptopus::lang::Primitive::I16 },
+        "i32" => quote! { ::interoptopus::lang::Primitive::I32 },
+        "i64" => quote! { ::interoptopus::lang::Primitive::I64 },
+        _ => panic!("Bitflags must be backed by a fixed-size integer (`u8` ... `u64`, `i8` ... `i64`)."),
+    }
+}
+
+pub fn ffi_type_bitflags(attributes: &Attributes, _input: TokenStream, item: &ItemMacro) -> TokenStream {
+    let parsed = match syn::parse2::<BitflagsStruct>(item.mac.tokens.clone()) {
+        Ok(x) => x,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let namespace = attributes.namespace.clone().unwrap_or_default();
+    let doc_line = extract_doc_lines(&parsed.attrs).join("\n");
+    let ffi_name = attributes.name.clone().unwrap_or_else(|| parsed.ident.to_string());
+    let primitive = bits_primitive(&parsed.bits);
+
+    let BitflagsStruct { attrs, vis, ident, bits, flags } = parsed;
+    let mac_attrs = &item.attrs;
+    let mac_path = &item.mac.path;
+
+    let flag_defs = flags.iter().map(|BitflagsConst { attrs, ident, expr }| quote! { #(#attrs)* const #ident = #expr; });
+
+    // Unnamed flags (`const _ = ...`) only mark bits as known and are not exported.
+    let flag_infos = flags.iter().filter(|x| x.ident != "_").map(|x| {
+        let ident = &x.ident;
+        let name = ident.to_string();
+        quote! { flags.push(::interoptopus::pattern::bitflags::Flag::new(#name.to_string(), Self::#ident.bits() as u64)); }
+    });
+
+    quote! {
+        #(#mac_attrs)*
+        #mac_path! {
+            #(#attrs)*
+            #[repr(transparent)]
+            #vis struct #ident: #bits {
+                #(#flag_defs)*
+            }
+        }
+
+        unsafe impl ::interoptopus::lang::TypeInfo for #ident {
+            #[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+            fn type_info() -> ::interoptopus::lang::Type {
+                let mut flags = ::std::vec::Vec::new();
+                let docs = ::interoptopus::lang::Docs::from_line(#doc_line);
+                let meta = ::interoptopus::lang::Meta::with_module_docs(#namespace.to_string(), docs);
+
+                #(#flag_infos)*
+
+                let rval = ::interoptopus::pattern::bitflags::BitflagsType::new(#ffi_name.to_string(), #primitive, flags, meta);
+                ::interoptopus::lang::Type::Pattern(::interoptopus::pattern::TypePattern::Bitflags(rval))
+            }
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use crate::macros::darling_parse;
+use crate::types::bitflags::ffi_type_bitflags;
 use crate::types::enums::ffi_type_enum;
 use crate::types::structs::ffi_type_struct;
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::collections::HashMap;
-use syn::{Field, ItemEnum, ItemStruct, ItemType, Visibility};
+use syn::{Field, ItemEnum, ItemMacro, ItemStruct, ItemType, Visibility};
 
+mod bitflags;
 mod enums;
 mod structs;
 
@@ -23,6 +25,9 @@ pub struct Attributes {
     #[darling(default)]
     packed: bool,
 
+    #[darling(default)]
+    poolable: bool,
+
     #[darling(default)]
     u8: bool,
 
@@ -116,8 +121,13 @@ pub fn ffi_type(attr: TokenStream, input: TokenStream) -> TokenStream {
         ffi_type_enum(&attributes, input, item)
     } else if let Ok(_item) = syn::parse2::<ItemType>(input.clone()) {
         input
+    } else if let Some(item) = syn::parse2::<ItemMacro>(input.clone())
+        .ok()
+        .filter(|x| x.mac.path.segments.last().is_some_and(|s| s.ident == "bitflags"))
+    {
+        ffi_type_bitflags(&attributes, input, &item)
     } else {
-        panic!("Annotation #[ffi_type] only works with structs and enum types.")
+        panic!("Annotation #[ffi_type] only works with structs, enum types and `bitflags!` invocations.")
     };
 
     if attributes.debug {
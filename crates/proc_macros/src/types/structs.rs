@@ -190,6 +190,7 @@ pub fn ffi_type_struct(attributes: &Attributes, _input: TokenStream, mut item: I
     );
 
     let align = align.map_or_else(|| quote! { None }, |x| quote! { Some(#x) });
+    let poolable = attributes.poolable;
 
     let layout = match type_repr {
         TypeRepresentation::C => quote! { ::interoptopus::lang::Layout::C },
@@ -214,7 +215,9 @@ pub fn ffi_type_struct(attributes: &Attributes, _input: TokenStream, mut item: I
     } else {
         quote! {
             let repr = ::interoptopus::lang::Representation::new(#layout, #align);
-            let rval = ::interoptopus::lang::Composite::with_meta_repr(name, fields, meta, repr);
+            let size = ::std::mem::size_of::<Self>();
+            let align = ::std::mem::align_of::<Self>();
+            let rval = ::interoptopus::lang::Composite::with_meta_repr(name, fields, meta, repr).with_size_align(size, align).with_poolable(#poolable);
             ::interoptopus::lang::Type::Composite(rval)
         }
     };
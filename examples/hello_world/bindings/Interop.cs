@@ -3,7 +3,7 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      library
-// Hash:         0x33a5a64b0afa2df2
+// Hash:         0x1cfde08d5de91d39
 // Namespace:    
 // Builder:      interoptopus_backend_csharp
 //
@@ -22,7 +22,6 @@ using System.Runtime.InteropServices;
 using System.Runtime.InteropServices.Marshalling;
 using System.Runtime.CompilerServices;
 using My.Company;
-using My.Company;
 #pragma warning restore 0105
 
 namespace My.Company
@@ -50,6 +49,12 @@ namespace My.Company
     {
         public float x;
         public float y;
+        public Vec2(float _x, float _y)
+        {
+            x = _x;
+            y = _y;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -136,6 +141,16 @@ namespace My.Company
         {
         }
     }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -241,15 +256,30 @@ namespace My.Company
         {
             var rval = new Utf8String();
             var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
 
-            fixed (byte* p = utf8Bytes)
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
             }
 
             return rval;
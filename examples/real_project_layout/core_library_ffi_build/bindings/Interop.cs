@@ -3,7 +3,7 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      core_library
-// Hash:         0xc1abecb8486b287a
+// Hash:         0xed541ffb80921d57
 // Namespace:    
 // Builder:      interoptopus_backend_csharp
 //
@@ -22,7 +22,6 @@ using System.Runtime.InteropServices;
 using System.Runtime.InteropServices.Marshalling;
 using System.Runtime.CompilerServices;
 using My.Company;
-using My.Company;
 #pragma warning restore 0105
 
 namespace My.Company
@@ -137,6 +136,13 @@ namespace My.Company
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out Error result)
+        {
+            if (string.Equals(value, "Fail", StringComparison.Ordinal)) { result = Fail; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(Error), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -527,27 +533,86 @@ namespace My.Company
         public static GameEngine New()
         {
             var self = new GameEngine();
-            self._context = Interop.game_engine_new().AsOk();
+            var result = Interop.game_engine_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out GameEngine service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out GameEngine service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.game_engine_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new GameEngine();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.game_engine_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.game_engine_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~GameEngine()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void PlaceObject([MarshalAs(UnmanagedType.LPStr)] string name, Vec2 position)
         {
-            Interop.game_engine_place_object(_context, name, position).AsOk();
+            try { Interop.game_engine_place_object(_context, name, position).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public uint NumObjects()
         {
-            return Interop.game_engine_num_objects(_context);
+            try { return Interop.game_engine_num_objects(_context); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static GameEngine FromHandle(IntPtr handle)
+        {
+            var self = new GameEngine();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `game_engine_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out GameEngine service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -561,6 +626,16 @@ namespace My.Company
         {
         }
     }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -666,15 +741,30 @@ namespace My.Company
         {
             var rval = new Utf8String();
             var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
 
-            fixed (byte* p = utf8Bytes)
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
             }
 
             return rval;
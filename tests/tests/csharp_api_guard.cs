@@ -3,7 +3,7 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      library
-// Hash:         0xaa7090aa4d54a2df
+// Hash:         0x5f6a0557f10e84ce
 // Namespace:    
 // Builder:      interoptopus_backend_csharp
 //
@@ -22,7 +22,6 @@ using System.Runtime.InteropServices;
 using System.Runtime.InteropServices.Marshalling;
 using System.Runtime.CompilerServices;
 using My.Company;
-using My.Company;
 #pragma warning restore 0105
 
 namespace My.Company
@@ -34,9 +33,9 @@ namespace My.Company
         static Interop()
         {
             var api_version = Interop.__api_guard();
-            if (api_version != 0xaa7090aa4d54a2df)
+            if (api_version != 0x5f6a0557f10e84ce)
             {
-                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0xaa7090aa4d54a2df). You probably forgot to update / copy either the bindings or the library.");
+                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0x5f6a0557f10e84ce). You probably forgot to update / copy either the bindings or the library.");
             }
         }
 
@@ -62,6 +61,16 @@ namespace My.Company
         {
         }
     }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -167,15 +176,30 @@ namespace My.Company
         {
             var rval = new Utf8String();
             var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
 
-            fixed (byte* p = utf8Bytes)
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
             }
 
             return rval;
@@ -1,6 +1,8 @@
 use anyhow::Error;
 use interoptopus::inventory::Bindings;
-use interoptopus::inventory::Inventory;
+use interoptopus::inventory::{Inventory, Symbol};
+use interoptopus::lang::{Composite, Field, Primitive, Type};
+use interoptopus::pattern::api_guard::ApiHash;
 use interoptopus::{api_guard, ffi_function, function};
 use interoptopus_backend_csharp::Interop;
 use tests::validate_output;
@@ -24,3 +26,16 @@ fn csharp_api_guard() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn api_hash_ignores_host_layout() {
+    let composite = Composite::new("Vec2".to_string(), vec![Field::new("x".to_string(), Type::Primitive(Primitive::F32))]);
+    let hash_of = |c: Composite| ApiHash::from(&Inventory::builder().register(Symbol::Type(Type::Composite(c))).build()).hash();
+
+    let without = hash_of(composite.clone());
+    let host_a = hash_of(composite.clone().with_size_align(4, 4));
+    let host_b = hash_of(composite.with_size_align(8, 8));
+
+    assert_eq!(without, host_a);
+    assert_eq!(host_a, host_b);
+}
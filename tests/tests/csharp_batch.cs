@@ -0,0 +1,556 @@
+// <auto-generated>
+//
+// This file was automatically generated by Interoptopus.
+//
+// Library:      library
+// Hash:         0xccce3a9c53f5894a
+// Namespace:    
+// Builder:      interoptopus_backend_csharp
+//
+// Do not edit this file manually.
+//
+// </auto-generated>
+
+
+#pragma warning disable 0105
+using System;
+using System.Text;
+using System.Threading.Tasks;
+using System.Collections;
+using System.Collections.Generic;
+using System.Runtime.InteropServices;
+using System.Runtime.InteropServices.Marshalling;
+using System.Runtime.CompilerServices;
+using My.Company;
+using My.Company.Common;
+#pragma warning restore 0105
+
+namespace My.Company
+{
+    public static partial class Interop
+    {
+        public const string NativeLib = "library";
+
+        static Interop()
+        {
+        }
+
+
+
+        [LibraryImport(NativeLib, EntryPoint = "submit_draw")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial uint submit_draw(SliceU8 commands);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "set_pixel")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void set_pixel(uint x, uint y, byte color);
+
+
+        /// Resets all pixels.
+        [LibraryImport(NativeLib, EntryPoint = "clear")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void clear();
+
+
+    }
+
+    public partial class SliceU8
+    {
+        GCHandle _handle;
+        IntPtr _data;
+        ulong _len;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SliceU8 : IEnumerable<byte>, IDisposable
+    {
+        public int Count => (int) _len;
+
+        public unsafe ReadOnlySpan<byte> ReadOnlySpan
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => new(_data.ToPointer(), (int)_len);
+        }
+
+        public unsafe byte this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                return Unsafe.Read<byte>((void*)IntPtr.Add(_data, i * Unsafe.SizeOf<byte>()));
+            }
+
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        SliceU8() { }
+
+        public static SliceU8 From(IntPtr data, ulong len)
+        {
+            var rval = new SliceU8();
+            rval._data = data;
+            rval._len = len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static SliceU8 From(byte[] managed)
+        {
+            var rval = new SliceU8();
+            rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
+            rval._data = rval._handle.AddrOfPinnedObject();
+            rval._len = (ulong) managed.Length;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IEnumerator<byte> GetEnumerator()
+        {
+            for (var i = 0; i < Count; ++i) { yield return this[i]; }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        IEnumerator IEnumerable.GetEnumerator() => GetEnumerator();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_handle is { IsAllocated: true }) { _handle.Free(); }
+            _data = IntPtr.Zero;
+        }
+
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._data = _data;
+            unmanaged._len = _len; 
+            return unmanaged;
+        }
+
+
+        [CustomMarshaller(typeof(SliceU8), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal SliceU8 ToManaged()
+            {
+                return SliceU8.From(_data, _len);
+            }
+        }
+
+        public ref struct Marshaller
+        {
+            private SliceU8 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SliceU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SliceU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SliceU8 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+        public static class SliceU8Extensions
+        {
+            public static SliceU8 Slice(this byte[] s) { return SliceU8.From(s); }
+        }
+
+
+
+    /// Records calls to batchable functions, executed by a single `submit_draw` call on `SubmitBatch()`.
+    public partial class DrawBatch
+    {
+        private byte[] _buffer;
+        private int _length;
+        private uint _count;
+
+        public DrawBatch(int capacity = 256)
+        {
+            _buffer = new byte[capacity];
+        }
+
+        /// The number of recorded commands.
+        public int CommandCount => (int) _count;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void SetPixel(uint x, uint y, byte color)
+        {
+            RecordCommand(0x99B5D744u, new SetPixelArgs { x = x, y = y, color = color });
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        private struct SetPixelArgs
+        {
+            public uint x;
+            public uint y;
+            public byte color;
+        }
+
+        /// Resets all pixels.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Clear()
+        {
+            RecordCommand(0x5C6E1222u, 0);
+        }
+
+        /// Executes all recorded commands in a single call and clears this batch.
+        public unsafe void SubmitBatch()
+        {
+            if (_count == 0) return;
+            var expected = _count;
+            uint executed;
+            fixed (byte* data = _buffer)
+            {
+                executed = Interop.submit_draw(SliceU8.From((IntPtr) data, (ulong) _length));
+            }
+            ClearCommands();
+            // Fewer executed commands mean the native side did not understand the buffer.
+            if (executed != expected) throw new InteropException();
+        }
+
+        /// Discards all recorded commands.
+        public void ClearCommands()
+        {
+            _length = 0;
+            _count = 0;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void RecordCommand(uint opcode, int size)
+        {
+            var required = _length + 8 + size;
+            if (required > _buffer.Length) Array.Resize(ref _buffer, Math.Max(required, _buffer.Length * 2));
+            Unsafe.WriteUnaligned(ref _buffer[_length], opcode);
+            Unsafe.WriteUnaligned(ref _buffer[_length + 4], (uint) size);
+            _length += 8;
+            _count++;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private void RecordCommand<T>(uint opcode, T args) where T : unmanaged
+        {
+            var size = Unsafe.SizeOf<T>();
+            RecordCommand(opcode, size);
+            Unsafe.WriteUnaligned(ref _buffer[_length], args);
+            _length += size;
+        }
+    }
+
+
+    public class InteropException : Exception
+    {
+        public InteropException() : base()
+        {
+        }
+    }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
+    public delegate void AsyncHelperDelegate(IntPtr data);
+
+    public partial struct AsyncHelper
+    {
+        private AsyncHelperDelegate _managed;
+        private AsyncHelperNative _native;
+        private IntPtr _ptr;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct AsyncHelper : IDisposable
+    {
+        public AsyncHelper() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public AsyncHelper(AsyncHelperDelegate managed)
+        {
+            _managed = managed;
+            _native = Call;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        void Call(IntPtr data, IntPtr _)
+        {
+            _managed(data);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            Marshal.FreeHGlobal(_ptr);
+            _ptr = IntPtr.Zero;
+        }
+
+        [CustomMarshaller(typeof(AsyncHelper), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr Callback;
+            internal IntPtr Data;
+        }
+
+        public ref struct Marshaller
+        {
+            private AsyncHelper _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(AsyncHelper managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged()
+            {
+                _unmanaged = new Unmanaged();
+                _unmanaged.Callback = _managed._ptr;
+                _unmanaged.Data = IntPtr.Zero;
+                return _unmanaged;
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public AsyncHelper ToManaged()
+            {
+                _managed = new AsyncHelper();
+                _managed._ptr = _unmanaged.Callback;
+                return _managed;
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() { }
+        }
+    }
+
+    public delegate void AsyncCallbackCommon(IntPtr data, IntPtr callback_data);
+
+    [StructLayout(LayoutKind.Sequential)]
+    public partial struct AsyncCallbackCommonNative
+    {
+        internal IntPtr _ptr;
+        internal IntPtr _ts;
+    }
+    public partial class Utf8String
+    {
+        IntPtr _ptr;
+        ulong _len;
+        ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class Utf8String : IDisposable
+    {
+        private Utf8String() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String From(string s)
+        {
+            var rval = new Utf8String();
+            var source = s.AsSpan();
+
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
+            {
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
+            }
+
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String Empty()
+        {
+            InteropHelper.interoptopus_string_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+
+        public unsafe string String
+        {
+            get
+            {
+                var span = new ReadOnlySpan<byte>((byte*)_ptr, (int)_len);
+                var s = Encoding.UTF8.GetString(span);
+                return s;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public string IntoString()
+        {
+            var rval = String;
+            Dispose();
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_string_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String Clone()
+        {
+            var _new = new Unmanaged();
+            var _this = AsUnmanaged();
+            InteropHelper.interoptopus_string_clone(ref _this, ref _new);
+            return _new.IntoManaged();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) { throw new Exception(); }
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            _ptr = IntPtr.Zero;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public IntPtr _ptr;
+            public ulong _len;
+            public ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Utf8String IntoManaged()
+            {
+                var _managed = new Utf8String();
+                _managed._ptr = _ptr;
+                _managed._len = _len;
+                _managed._capacity = _capacity;
+                return _managed;
+            }
+
+        }
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_create")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_destroy")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_destroy(Unmanaged utf8);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_clone")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_clone(ref Unmanaged orig, ref Unmanaged cloned);
+        }
+
+        [CustomMarshaller(typeof(Utf8String), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private Utf8String _managed; // Used when converting managed -> unmanaged
+            private Unmanaged _unmanaged; // Used when converting unmanaged -> managed
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Utf8String managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Utf8String managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public unsafe Unmanaged ToUnmanaged()
+            {
+                return _managed.IntoUnmanaged();
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public unsafe Utf8String ToManaged()
+            {
+                return _unmanaged.IntoManaged();
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() { }
+        }
+    }
+
+    public static class StringExtensions
+    {
+        public static Utf8String Utf8(this string s) { return Utf8String.From(s); }
+    }
+}
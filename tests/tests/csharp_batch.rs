@@ -0,0 +1,95 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::pattern::batch::{opcode, Batchable};
+use interoptopus::{batch, ffi, ffi_function, pattern};
+use interoptopus_backend_csharp::Interop;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+static PIXELS: AtomicU32 = AtomicU32::new(0);
+
+#[ffi_function(batchable)]
+pub fn set_pixel(x: u32, y: u32, color: u8) {
+    PIXELS.fetch_add(x + y + u32::from(color), Ordering::SeqCst);
+}
+
+/// Resets all pixels.
+#[ffi_function(batchable)]
+pub fn clear() {
+    PIXELS.store(0, Ordering::SeqCst);
+}
+
+#[ffi_function(batchable)]
+pub fn submit_batch() {}
+
+batch!(DrawBatch, submit_draw, [set_pixel, clear]);
+batch!(CollidingBatch, submit_colliding, [submit_batch]);
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(pattern!(DrawBatch)).validate().build()
+}
+
+fn encode(buffer: &mut Vec<u8>, opcode: u32, payload: &[u8]) {
+    buffer.extend_from_slice(&opcode.to_ne_bytes());
+    buffer.extend_from_slice(&(payload.len() as u32).to_ne_bytes());
+    buffer.extend_from_slice(payload);
+}
+
+#[test]
+fn submit_decodes_commands() {
+    let mut pixel = Vec::new();
+    pixel.extend_from_slice(&1u32.to_ne_bytes());
+    pixel.extend_from_slice(&2u32.to_ne_bytes());
+    pixel.extend_from_slice(&[3, 0, 0, 0]);
+
+    let mut buffer = Vec::new();
+    encode(&mut buffer, <clear as Batchable>::OPCODE, &[]);
+    encode(&mut buffer, opcode("set_pixel"), &pixel);
+    encode(&mut buffer, opcode("set_pixel"), &pixel);
+    encode(&mut buffer, opcode("set_pixel"), &pixel[..4]);
+    encode(&mut buffer, opcode("set_pixel"), &pixel);
+
+    // The truncated fourth command stops execution.
+    assert_eq!(submit_draw(ffi::Slice::from_slice(&buffer)), 3);
+    assert_eq!(PIXELS.load(Ordering::SeqCst), 12);
+}
+
+#[test]
+fn batch_encoders() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    let set_pixel = opcode("set_pixel");
+
+    assert!(generated.contains("public partial class DrawBatch"));
+    assert!(generated.contains("public void SetPixel(uint x, uint y, byte color)"));
+    assert!(generated.contains(&format!("RecordCommand(0x{set_pixel:08X}u, new SetPixelArgs {{ x = x, y = y, color = color }});")));
+    assert!(generated.contains("public void Clear()"));
+    assert!(generated.contains("public void ClearCommands()"));
+    assert!(generated.contains("public unsafe void SubmitBatch()"));
+    assert!(generated.contains("public int CommandCount => (int) _count;"));
+    assert!(generated.contains("executed = Interop.submit_draw(SliceU8.From((IntPtr) data, (ulong) _length));"));
+
+    validate_output!("tests", "csharp_batch.cs", generated.as_str());
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "can not emit `SubmitBatch` for `submit_batch`, it collides with a generated member")]
+fn batch_rejects_colliding_commands() {
+    let inventory = Inventory::builder().register(pattern!(CollidingBatch)).validate().build();
+
+    let interop = Interop::builder()
+        .inventory(inventory)
+        .namespace_mappings(common_namespace_mappings())
+        .build()
+        .unwrap();
+
+    let _ = interop.to_string();
+}
@@ -0,0 +1,54 @@
+use anyhow::Error;
+use bitflags::bitflags;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi_function, ffi_type, function};
+use interoptopus_backend_csharp::Interop;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type]
+bitflags! {
+    /// Access rights of a file.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct Permissions: u32 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+        const READ_WRITE = Self::READ.bits() | Self::WRITE.bits();
+        const _ = !0;
+    }
+}
+
+#[ffi_function]
+pub fn set_permissions(x: Permissions) -> Permissions {
+    x | Permissions::EXECUTE
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(function!(set_permissions)).validate().build()
+}
+
+#[test]
+fn bitflags_are_transparent() {
+    assert_eq!(size_of::<Permissions>(), size_of::<u32>());
+    assert_eq!(set_permissions(Permissions::READ), Permissions::READ | Permissions::EXECUTE);
+}
+
+#[test]
+fn bitflags_become_flags_enum() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("[Flags]"));
+    assert!(generated.contains("public enum Permissions : uint"));
+    assert!(generated.contains("READ_WRITE = 3,"));
+    assert!(generated.contains("public static partial Permissions set_permissions(Permissions x);"));
+
+    validate_output!("tests", "csharp_bitflags.cs", generated.as_str());
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::pattern::slice::Slice;
+use interoptopus::{builtins_string, ffi, ffi_function, function};
+use interoptopus_backend_csharp::{Interop, WriteTypes};
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_function]
+fn sample_function(_bytes: Slice<u8>, _text: ffi::String, _flag: ffi::Bool) {}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(function!(sample_function)).register(builtins_string!()).build()
+}
+
+#[test]
+fn builtins_in_shared_namespace() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .builtins_namespace("Interoptopus.Runtime".to_string())
+        .build()?
+        .to_string()?;
+
+    let (own, shared) = generated.split_once("namespace Interoptopus.Runtime").expect("builtins namespace must be emitted");
+
+    assert!(own.contains("using Interoptopus.Runtime;"));
+    assert!(own.contains("public static partial void sample_function(SliceU8 bytes, Utf8String text, Bool flag);"));
+    assert!(!own.contains("public partial class Utf8String"));
+    assert!(shared.contains("public partial class SliceU8"));
+    assert!(shared.contains("public partial struct Bool"));
+    assert!(shared.contains("public partial class Utf8String"));
+    assert!(shared.contains("public partial class InteropHelper"));
+
+    validate_output!("tests", "csharp_builtins_namespace.cs", generated.as_str());
+
+    Ok(())
+}
+
+#[test]
+fn builtins_referenced_only() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .builtins_namespace("Interoptopus.Runtime".to_string())
+        .write_types(WriteTypes::Namespace)
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("using Interoptopus.Runtime;"));
+    assert!(generated.contains("public static partial void sample_function(SliceU8 bytes, Utf8String text, Bool flag);"));
+    assert!(!generated.contains("namespace Interoptopus.Runtime"));
+    assert!(!generated.contains("public partial class SliceU8"));
+    assert!(!generated.contains("public partial struct Bool"));
+    assert!(!generated.contains("public partial class Utf8String"));
+
+    validate_output!("tests", "csharp_builtins_namespace_referenced.cs", generated.as_str());
+
+    Ok(())
+}
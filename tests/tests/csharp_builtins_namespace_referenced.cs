@@ -0,0 +1,64 @@
+// <auto-generated>
+//
+// This file was automatically generated by Interoptopus.
+//
+// Library:      library
+// Hash:         0xc584b742e629ebad
+// Namespace:    
+// Builder:      interoptopus_backend_csharp
+//
+// Do not edit this file manually.
+//
+// </auto-generated>
+
+
+#pragma warning disable 0105
+using System;
+using System.Text;
+using System.Threading.Tasks;
+using System.Collections;
+using System.Collections.Generic;
+using System.Runtime.InteropServices;
+using System.Runtime.InteropServices.Marshalling;
+using System.Runtime.CompilerServices;
+using Interoptopus.Runtime;
+using My.Company;
+using My.Company.Common;
+#pragma warning restore 0105
+
+namespace My.Company
+{
+    public static partial class Interop
+    {
+        public const string NativeLib = "library";
+
+        static Interop()
+        {
+        }
+
+
+
+        [LibraryImport(NativeLib, EntryPoint = "sample_function")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void sample_function(SliceU8 bytes, Utf8String text, Bool flag);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_create")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_string_create(IntPtr utf8, ulong len, ref Utf8String rval);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_string_destroy(Utf8String utf8);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "interoptopus_string_clone")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial long interoptopus_string_clone(ref Utf8String utf8, ref Utf8String rval);
+
+
+    }
+
+
+}
@@ -22,7 +22,6 @@ using System.Runtime.InteropServices;
 using System.Runtime.InteropServices.Marshalling;
 using System.Runtime.CompilerServices;
 using My.Company;
-using My.Company;
 using My.Company.Common;
 #pragma warning restore 0105
 
@@ -39,6 +38,14 @@ namespace My.Company
     {
 
 
+        public MyEnumEnum AsEnum() => (MyEnumEnum) _variant;
+        public static implicit operator MyEnumEnum(MyEnum value) => value.AsEnum();
+
+        public enum MyEnumEnum : uint
+        {
+            Enumerator = 0,
+        }
+
         [StructLayout(LayoutKind.Explicit)]
         public unsafe struct Unmanaged
         {
@@ -76,6 +83,11 @@ namespace My.Company
 
         public void AsEnumerator() { if (_variant != 0) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsEnumeratorOrElse(Action<MyEnum> cb) { if (_variant != 0) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -83,6 +95,13 @@ namespace My.Company
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out MyEnum result)
+        {
+            if (string.Equals(value, "Enumerator", StringComparison.Ordinal)) { result = Enumerator; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(MyEnum), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -354,6 +373,16 @@ namespace My.Company
         {
         }
     }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -459,15 +488,30 @@ namespace My.Company
         {
             var rval = new Utf8String();
             var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
 
-            fixed (byte* p = utf8Bytes)
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
             }
 
             return rval;
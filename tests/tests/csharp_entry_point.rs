@@ -0,0 +1,35 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{builtins_string, ffi_function, function};
+use interoptopus_backend_csharp::Interop;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_function]
+fn sample_function(x: u32) -> u32 {
+    x
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(function!(sample_function)).register(builtins_string!()).build()
+}
+
+#[test]
+fn entry_point_transformed() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .entry_point_prefix("lib_")
+        .entry_point_suffix("_v2")
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains(r#"EntryPoint = "lib_sample_function_v2""#));
+    assert!(generated.contains("public static partial uint sample_function(uint x);"));
+    assert!(generated.contains(r#"EntryPoint = "interoptopus_string_create""#));
+
+    validate_output!("tests", "csharp_entry_point.cs", generated.as_str());
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi_function, ffi_type, function};
+use interoptopus_backend_csharp::Interop;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warning,
+}
+
+#[ffi_function]
+pub fn set_log_level(_level: LogLevel) {}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(function!(set_log_level)).validate().build()
+}
+
+#[test]
+fn try_parse_case_sensitive() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("public static bool TryParse(string value, out LogLevel result)"));
+    assert!(generated.contains(r#"if (string.Equals(value, "Warning", StringComparison.Ordinal)) { result = Warning; return true; }"#));
+
+    validate_output!("tests", "csharp_enum_parse.cs", generated.as_str());
+
+    Ok(())
+}
+
+#[test]
+fn try_parse_ignore_case() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .enum_parse_ignore_case(true)
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains(r#"if (string.Equals(value, "Debug", StringComparison.OrdinalIgnoreCase)) { result = Debug; return true; }"#));
+    assert!(!generated.contains("StringComparison.Ordinal)"));
+
+    Ok(())
+}
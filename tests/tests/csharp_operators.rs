@@ -0,0 +1,63 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi_function, ffi_type, function};
+use interoptopus_backend_csharp::Interop;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type]
+#[derive(Copy, Clone)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[ffi_function(operator_add_of = "Vec3")]
+pub fn vec3_add(a: Vec3, b: Vec3) -> Vec3 {
+    Vec3 { x: a.x + b.x, y: a.y + b.y, z: a.z + b.z }
+}
+
+#[ffi_function(operator_sub_of = "Vec3")]
+pub fn vec3_sub(a: &Vec3, b: &Vec3) -> Vec3 {
+    Vec3 { x: a.x - b.x, y: a.y - b.y, z: a.z - b.z }
+}
+
+#[ffi_function(operator_mul_of = "Vec3")]
+pub fn vec3_scale(a: Vec3, s: f32) -> Vec3 {
+    Vec3 { x: a.x * s, y: a.y * s, z: a.z * s }
+}
+
+#[ffi_function]
+pub fn vec3_dot(a: Vec3, b: Vec3) -> f32 {
+    a.z.mul_add(b.z, a.x.mul_add(b.x, a.y * b.y))
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder()
+        .register(function!(vec3_add))
+        .register(function!(vec3_sub))
+        .register(function!(vec3_scale))
+        .register(function!(vec3_dot))
+        .validate()
+        .build()
+}
+
+#[test]
+fn operators() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("public static Vec3 operator +(Vec3 a, Vec3 b) => Interop.vec3_add(a, b);"));
+    assert!(generated.contains("public static Vec3 operator -(Vec3 a, Vec3 b) => Interop.vec3_sub(ref a, ref b);"));
+    assert!(generated.contains("public static Vec3 operator *(Vec3 a, float s) => Interop.vec3_scale(a, s);"));
+    assert!(!generated.contains("operator /"));
+
+    validate_output!("tests", "csharp_operators.cs", generated.as_str());
+
+    Ok(())
+}
@@ -3,7 +3,7 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      library
-// Hash:         0xc4c42a56d8c2b9a7
+// Hash:         0xcbbcba9af28203c1
 // Namespace:    
 // Builder:      interoptopus_backend_csharp
 //
@@ -22,7 +22,6 @@ using System.Runtime.InteropServices;
 using System.Runtime.InteropServices.Marshalling;
 using System.Runtime.CompilerServices;
 using My.Company;
-using My.Company;
 using My.Company.Common;
 #pragma warning restore 0105
 
@@ -35,9 +34,9 @@ namespace My.Company
         static Interop()
         {
             var api_version = Interop.pattern_api_guard();
-            if (api_version != 0xc4c42a56d8c2b9a7)
+            if (api_version != 0xcbbcba9af28203c1)
             {
-                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0xc4c42a56d8c2b9a7). You probably forgot to update / copy either the bindings or the library.");
+                throw new TypeLoadException($"API reports hash 0x{api_version:X} which differs from hash in bindings (0xcbbcba9af28203c1). You probably forgot to update / copy either the bindings or the library.");
             }
         }
 
@@ -1489,6 +1488,16 @@ namespace My.Company
 
 
 
+        public EnumDocumentedEnum AsEnum() => (EnumDocumentedEnum) _variant;
+        public static implicit operator EnumDocumentedEnum(EnumDocumented value) => value.AsEnum();
+
+        public enum EnumDocumentedEnum : uint
+        {
+            A = 0,
+            B = 1,
+            C = 2,
+        }
+
         [StructLayout(LayoutKind.Explicit)]
         public unsafe struct Unmanaged
         {
@@ -1532,6 +1541,13 @@ namespace My.Company
         public void AsB() { if (_variant != 1) throw new InteropException(); }
         public void AsC() { if (_variant != 2) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsAOrElse(Action<EnumDocumented> cb) { if (_variant != 0) cb(this); }
+        public void AsBOrElse(Action<EnumDocumented> cb) { if (_variant != 1) cb(this); }
+        public void AsCOrElse(Action<EnumDocumented> cb) { if (_variant != 2) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -1541,6 +1557,15 @@ namespace My.Company
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out EnumDocumented result)
+        {
+            if (string.Equals(value, "A", StringComparison.Ordinal)) { result = A; return true; }
+            if (string.Equals(value, "B", StringComparison.Ordinal)) { result = B; return true; }
+            if (string.Equals(value, "C", StringComparison.Ordinal)) { result = C; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(EnumDocumented), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -1653,6 +1678,15 @@ namespace My.Company
         public void AsB() { if (_variant != 1) throw new InteropException(); }
         public nint AsC() { if (_variant != 2) { throw new InteropException(); } else { return _C; } }
 
+        #nullable enable
+        public nuint? AsAOrNull() => _variant == 0 ? _A : null;
+        public nint? AsCOrNull() => _variant == 2 ? _C : null;
+        #nullable disable
+
+        public nuint AsAOrElse(Func<EnumNum, nuint> cb) => _variant == 0 ? _A : cb(this);
+        public void AsBOrElse(Action<EnumNum> cb) { if (_variant != 1) cb(this); }
+        public nint AsCOrElse(Func<EnumNum, nint> cb) => _variant == 2 ? _C : cb(this);
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -1774,6 +1808,15 @@ namespace My.Company
         public Vec3f32 AsB() { if (_variant != 1) { throw new InteropException(); } else { return _B; } }
         public uint AsC() { if (_variant != 2) { throw new InteropException(); } else { return _C; } }
 
+        #nullable enable
+        public Vec3f32? AsBOrNull() => _variant == 1 ? _B : null;
+        public uint? AsCOrNull() => _variant == 2 ? _C : null;
+        #nullable disable
+
+        public void AsAOrElse(Action<EnumPayload> cb) { if (_variant != 0) cb(this); }
+        public Vec3f32 AsBOrElse(Func<EnumPayload, Vec3f32> cb) => _variant == 1 ? _B : cb(this);
+        public uint AsCOrElse(Func<EnumPayload, uint> cb) => _variant == 2 ? _C : cb(this);
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -1824,6 +1867,14 @@ namespace My.Company
     {
 
 
+        public EnumRenamedEnum AsEnum() => (EnumRenamedEnum) _variant;
+        public static implicit operator EnumRenamedEnum(EnumRenamed value) => value.AsEnum();
+
+        public enum EnumRenamedEnum : uint
+        {
+            X = 0,
+        }
+
         [StructLayout(LayoutKind.Explicit)]
         public unsafe struct Unmanaged
         {
@@ -1861,6 +1912,11 @@ namespace My.Company
 
         public void AsX() { if (_variant != 0) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsXOrElse(Action<EnumRenamed> cb) { if (_variant != 0) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -1868,6 +1924,13 @@ namespace My.Company
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out EnumRenamed result)
+        {
+            if (string.Equals(value, "X", StringComparison.Ordinal)) { result = X; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(EnumRenamed), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -1909,6 +1972,14 @@ namespace My.Company
     {
 
 
+        public ErrorEnum AsEnum() => (ErrorEnum) _variant;
+        public static implicit operator ErrorEnum(Error value) => value.AsEnum();
+
+        public enum ErrorEnum : uint
+        {
+            Fail = 0,
+        }
+
         [StructLayout(LayoutKind.Explicit)]
         public unsafe struct Unmanaged
         {
@@ -1946,6 +2017,11 @@ namespace My.Company
 
         public void AsFail() { if (_variant != 0) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsFailOrElse(Action<Error> cb) { if (_variant != 0) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -1953,6 +2029,13 @@ namespace My.Company
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out Error result)
+        {
+            if (string.Equals(value, "Fail", StringComparison.Ordinal)) { result = Fail; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(Error), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -2066,6 +2149,14 @@ namespace My.Company
         public Layer1Utf8String AsA() { if (_variant != 0) { throw new InteropException(); } else { return _A; } }
         public Layer2Utf8String AsB() { if (_variant != 1) { throw new InteropException(); } else { return _B; } }
 
+        #nullable enable
+        public Layer1Utf8String? AsAOrNull() => _variant == 0 ? _A : null;
+        public Layer2Utf8String? AsBOrNull() => _variant == 1 ? _B : null;
+        #nullable disable
+
+        public Layer1Utf8String AsAOrElse(Func<Layer3, Layer1Utf8String> cb) => _variant == 0 ? _A : cb(this);
+        public Layer2Utf8String AsBOrElse(Func<Layer3, Layer2Utf8String> cb) => _variant == 1 ? _B : cb(this);
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2108,6 +2199,11 @@ namespace My.Company
     public partial struct Array
     {
         public byte[] data;
+        public Array(byte[] _data)
+        {
+            data = _data;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2203,6 +2299,11 @@ namespace My.Company
     public partial struct BoolField
     {
         public bool val;
+        public BoolField(bool _val)
+        {
+            val = _val;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2287,6 +2388,18 @@ namespace My.Company
         public SumDelegate2 sum_delegate_2;
         public SumDelegateReturn sum_delegate_return;
         public SumDelegateReturn2 sum_delegate_return_2;
+        public CallbackTable(MyCallback _my_callback, MyCallbackNamespaced _my_callback_namespaced, MyCallbackVoid _my_callback_void, MyCallbackContextual _my_callback_contextual, SumDelegate1 _sum_delegate_1, SumDelegate2 _sum_delegate_2, SumDelegateReturn _sum_delegate_return, SumDelegateReturn2 _sum_delegate_return_2)
+        {
+            my_callback = _my_callback;
+            my_callback_namespaced = _my_callback_namespaced;
+            my_callback_void = _my_callback_void;
+            my_callback_contextual = _my_callback_contextual;
+            sum_delegate_1 = _sum_delegate_1;
+            sum_delegate_2 = _sum_delegate_2;
+            sum_delegate_return = _sum_delegate_return;
+            sum_delegate_return_2 = _sum_delegate_return_2;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2404,6 +2517,12 @@ namespace My.Company
     {
         public FixedString str;
         public FixedString str_2;
+        public CharArray(FixedString _str, FixedString _str_2)
+        {
+            str = _str;
+            str_2 = _str_2;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2485,6 +2604,11 @@ namespace My.Company
     public partial struct Container
     {
         public Local foreign;
+        public Container(Local _foreign)
+        {
+            foreign = _foreign;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2562,6 +2686,11 @@ namespace My.Company
     public partial struct ExtraTypef32
     {
         public float x;
+        public ExtraTypef32(float _x)
+        {
+            x = _x;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2639,6 +2768,11 @@ namespace My.Company
     public partial struct FixedString
     {
         public byte[] data;
+        public FixedString(byte[] _data)
+        {
+            data = _data;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2734,6 +2868,11 @@ namespace My.Company
     public partial struct Genericu32
     {
         public IntPtr x;
+        public Genericu32(IntPtr _x)
+        {
+            x = _x;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2811,6 +2950,11 @@ namespace My.Company
     public partial struct Genericu8
     {
         public IntPtr x;
+        public Genericu8(IntPtr _x)
+        {
+            x = _x;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2890,6 +3034,13 @@ namespace My.Company
         public nint x;
         public nint y;
         public nint z;
+        public IVec3(nint _x, nint _y, nint _z)
+        {
+            x = _x;
+            y = _y;
+            z = _z;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -2975,6 +3126,11 @@ namespace My.Company
     public partial struct Inner
     {
         public float x;
+        public Inner(float _x)
+        {
+            x = _x;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3054,6 +3210,13 @@ namespace My.Company
         public OptionUtf8String maybe_1;
         public VecUtf8String maybe_2;
         public Utf8String maybe_3;
+        public Layer1Utf8String(OptionUtf8String _maybe_1, VecUtf8String _maybe_2, Utf8String _maybe_3)
+        {
+            maybe_1 = _maybe_1;
+            maybe_2 = _maybe_2;
+            maybe_3 = _maybe_3;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3148,6 +3311,14 @@ namespace My.Company
         public Vec3f32 vec;
         public EnumPayload the_enum;
         public VecUtf8String strings;
+        public Layer2Utf8String(Layer1Utf8String _layer_1, Vec3f32 _vec, EnumPayload _the_enum, VecUtf8String _strings)
+        {
+            layer_1 = _layer_1;
+            vec = _vec;
+            the_enum = _the_enum;
+            strings = _strings;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3325,6 +3496,17 @@ namespace My.Company
         public ushort[] field_array;
         public ushort[] field_array_2;
         public Array field_struct;
+        public NestedArray(EnumRenamed _field_enum, Vec3f32 _field_vec, bool _field_bool, int _field_int, ushort[] _field_array, ushort[] _field_array_2, Array _field_struct)
+        {
+            field_enum = _field_enum;
+            field_vec = _field_vec;
+            field_bool = _field_bool;
+            field_int = _field_int;
+            field_array = _field_array;
+            field_array_2 = _field_array_2;
+            field_struct = _field_struct;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3463,6 +3645,12 @@ namespace My.Company
     {
         public byte x;
         public ushort y;
+        public Packed1(byte _x, ushort _y)
+        {
+            x = _x;
+            y = _y;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3545,6 +3733,12 @@ namespace My.Company
     {
         public ushort y;
         public byte x;
+        public Packed2(ushort _y, byte _x)
+        {
+            y = _y;
+            x = _x;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3626,6 +3820,11 @@ namespace My.Company
     public partial struct Phantomu8
     {
         public uint x;
+        public Phantomu8(uint _x)
+        {
+            x = _x;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3705,6 +3904,11 @@ namespace My.Company
     {
         /// Documented field.
         public float x;
+        public StructDocumented(float _x)
+        {
+            x = _x;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3782,6 +3986,11 @@ namespace My.Company
     public partial struct StructRenamed
     {
         public EnumRenamed e;
+        public StructRenamed(EnumRenamed _e)
+        {
+            e = _e;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3859,6 +4068,11 @@ namespace My.Company
     public partial struct Tupled
     {
         public byte x0;
+        public Tupled(byte _x0)
+        {
+            x0 = _x0;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -3936,6 +4150,11 @@ namespace My.Company
     public partial struct UseCStrPtr
     {
         public string ascii_string;
+        public UseCStrPtr(string _ascii_string)
+        {
+            ascii_string = _ascii_string;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -4014,6 +4233,12 @@ namespace My.Company
     {
         public SliceUtf8String s1;
         public VecUtf8String s2;
+        public UseSliceAndVec(SliceUtf8String _s1, VecUtf8String _s2)
+        {
+            s1 = _s1;
+            s2 = _s2;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -4101,6 +4326,12 @@ namespace My.Company
     {
         public Utf8String s1;
         public Utf8String s2;
+        public UseString(Utf8String _s1, Utf8String _s2)
+        {
+            s1 = _s1;
+            s2 = _s2;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -4188,6 +4419,12 @@ namespace My.Company
     {
         public float x;
         public float y;
+        public Vec1(float _x, float _y)
+        {
+            x = _x;
+            y = _y;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -4270,6 +4507,12 @@ namespace My.Company
     {
         public double x;
         public double z;
+        public Vec2(double _x, double _z)
+        {
+            x = _x;
+            z = _z;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -4353,6 +4596,13 @@ namespace My.Company
         public float x;
         public float y;
         public float z;
+        public Vec3f32(float _x, float _y, float _z)
+        {
+            x = _x;
+            y = _y;
+            z = _z;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -4521,6 +4771,12 @@ namespace My.Company
     {
         public byte pblc1;
         public byte pblc2;
+        public Visibility2(byte _pblc1, byte _pblc2)
+        {
+            pblc1 = _pblc1;
+            pblc2 = _pblc2;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -5682,6 +5938,7 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static SliceVec3f32 From(Vec3f32[] managed)
         {
+            AssertElementLayout();
             var rval = new SliceVec3f32();
             rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
             rval._data = rval._handle.AddrOfPinnedObject();
@@ -5714,6 +5971,22 @@ namespace My.Company
         }
 
 
+        [System.Diagnostics.Conditional("DEBUG")]
+        static void AssertElementLayout()
+        {
+            var size = Unsafe.SizeOf<Vec3f32>();
+            var align = (int) Marshal.OffsetOf<ElementAlignment>(nameof(ElementAlignment.element));
+            System.Diagnostics.Debug.Assert(size == 12, $"Size of 'Vec3f32' is {size} bytes in C#, but 12 in Rust.");
+            System.Diagnostics.Debug.Assert(align == 4, $"Alignment of 'Vec3f32' is {align} bytes in C#, but 4 in Rust.");
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        struct ElementAlignment
+        {
+            public byte padding;
+            public Vec3f32 element;
+        }
+
         [CustomMarshaller(typeof(SliceVec3f32), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -5813,6 +6086,7 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static SliceMutCharArray From(CharArray[] managed)
         {
+            AssertElementLayout();
             var rval = new SliceMutCharArray();
             rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
             rval._data = rval._handle.AddrOfPinnedObject();
@@ -5845,6 +6119,22 @@ namespace My.Company
         }
 
 
+        [System.Diagnostics.Conditional("DEBUG")]
+        static void AssertElementLayout()
+        {
+            var size = Unsafe.SizeOf<CharArray>();
+            var align = (int) Marshal.OffsetOf<ElementAlignment>(nameof(ElementAlignment.element));
+            System.Diagnostics.Debug.Assert(size == 64, $"Size of 'CharArray' is {size} bytes in C#, but 64 in Rust.");
+            System.Diagnostics.Debug.Assert(align == 1, $"Alignment of 'CharArray' is {align} bytes in C#, but 1 in Rust.");
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        struct ElementAlignment
+        {
+            public byte padding;
+            public CharArray element;
+        }
+
         [CustomMarshaller(typeof(SliceMutCharArray), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -6222,6 +6512,13 @@ namespace My.Company
         public EnumPayload AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public EnumPayload? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public EnumPayload AsSomeOrElse(Func<OptionEnumPayload, EnumPayload> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionEnumPayload> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -6326,6 +6623,13 @@ namespace My.Company
         public Inner AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public Inner? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public Inner AsSomeOrElse(Func<OptionInner, Inner> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionInner> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -6434,6 +6738,13 @@ namespace My.Company
         public OptionResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public OptionResultOptionUtf8StringError? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public OptionResultOptionUtf8StringError AsSomeOrElse(Func<OptionOptionResultOptionUtf8StringError, OptionResultOptionUtf8StringError> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionOptionResultOptionUtf8StringError> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -6542,6 +6853,13 @@ namespace My.Company
         public ResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public ResultOptionUtf8StringError? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public ResultOptionUtf8StringError AsSomeOrElse(Func<OptionResultOptionUtf8StringError, ResultOptionUtf8StringError> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionResultOptionUtf8StringError> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -6650,6 +6968,13 @@ namespace My.Company
         public Utf8String AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public Utf8String? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public Utf8String AsSomeOrElse(Func<OptionUtf8String, Utf8String> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionUtf8String> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -6775,6 +7100,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceAsyncBasicError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceAsyncBasicError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceAsyncBasicError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceAsyncBasicError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -6902,6 +7237,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceAsyncResultError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceAsyncResultError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceAsyncResultError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceAsyncResultError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7029,6 +7374,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceAsyncSleepError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceAsyncSleepError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceAsyncSleepError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceAsyncSleepError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7156,6 +7511,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceAsyncStructsError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceAsyncStructsError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceAsyncStructsError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceAsyncStructsError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7283,6 +7648,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceAsyncVecStringError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceAsyncVecStringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceAsyncVecStringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceAsyncVecStringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7410,6 +7785,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceBasicError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceBasicError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceBasicError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceBasicError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7537,6 +7922,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceCallbacksError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceCallbacksError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceCallbacksError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceCallbacksError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7664,6 +8059,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceDependentError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceDependentError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceDependentError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceDependentError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7791,6 +8196,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceIgnoringMethodsError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceIgnoringMethodsError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceIgnoringMethodsError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceIgnoringMethodsError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -7918,6 +8333,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceMainError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceMainError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceMainError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceMainError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8045,6 +8470,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceMultipleCtorsError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceMultipleCtorsError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceMultipleCtorsError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceMultipleCtorsError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8172,6 +8607,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceOnPanicError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceOnPanicError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceOnPanicError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceOnPanicError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8299,6 +8744,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceResultError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceResultError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceResultError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceResultError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8426,6 +8881,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceStringsError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceStringsError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceStringsError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceStringsError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8553,6 +9018,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceVariousSlicesError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceVariousSlicesError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceVariousSlicesError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceVariousSlicesError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8667,6 +9142,15 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public void AsOkOrElse(Action<ResultError> cb) { if (_variant != 0) cb(this); }
+        public Error AsErrOrElse(Func<ResultError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8794,6 +9278,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public NestedArray? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public NestedArray AsOkOrElse(Func<ResultNestedArrayError, NestedArray> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultNestedArrayError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultNestedArrayError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultNestedArrayError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -8921,6 +9415,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public OptionEnumPayload? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public OptionEnumPayload AsOkOrElse(Func<ResultOptionEnumPayloadError, OptionEnumPayload> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultOptionEnumPayloadError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultOptionEnumPayloadError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultOptionEnumPayloadError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -9052,6 +9556,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public OptionUtf8String? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public OptionUtf8String AsOkOrElse(Func<ResultOptionUtf8StringError, OptionUtf8String> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultOptionUtf8StringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultOptionUtf8StringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultOptionUtf8StringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -9179,6 +9693,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public uint? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public uint AsOkOrElse(Func<ResultU32Error, uint> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultU32Error, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultU32Error> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultU32Error> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -9306,6 +9830,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public ulong? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public ulong AsOkOrElse(Func<ResultU64Error, ulong> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultU64Error, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultU64Error> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultU64Error> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -9437,6 +9971,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public UseString? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public UseString AsOkOrElse(Func<ResultUseStringError, UseString> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultUseStringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultUseStringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultUseStringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -9568,6 +10112,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public Utf8String? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public Utf8String AsOkOrElse(Func<ResultUtf8StringError, Utf8String> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultUtf8StringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultUtf8StringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultUtf8StringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -9699,6 +10253,16 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public VecUtf8String? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public VecUtf8String AsOkOrElse(Func<ResultVecUtf8StringError, VecUtf8String> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultVecUtf8StringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultVecUtf8StringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultVecUtf8StringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -9800,6 +10364,14 @@ namespace My.Company
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsOkOrElse(Action<ResultVoid> cb) { if (_variant != 0) cb(this); }
+        public void AsErrOrElse(Action<ResultVoid> cb) { if (_variant != 1) cb(this); }
+        public void AsPanicOrElse(Action<ResultVoid> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultVoid> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -12417,21 +12989,79 @@ namespace My.Company
         public static ServiceAsyncBasic New()
         {
             var self = new ServiceAsyncBasic();
-            self._context = Interop.service_async_basic_new().AsOk();
+            var result = Interop.service_async_basic_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncBasic service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncBasic service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_async_basic_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceAsyncBasic();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_basic_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_async_basic_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceAsyncBasic()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task Call()
         {
-            return Interop.service_async_basic_call(_context);
+            try { return Interop.service_async_basic_call(_context); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncBasic FromHandle(IntPtr handle)
+        {
+            var self = new ServiceAsyncBasic();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_async_basic_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceAsyncBasic service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12448,21 +13078,79 @@ namespace My.Company
         public static ServiceAsyncSleep New()
         {
             var self = new ServiceAsyncSleep();
-            self._context = Interop.service_async_sleep_new().AsOk();
+            var result = Interop.service_async_sleep_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncSleep service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncSleep service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_async_sleep_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceAsyncSleep();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_sleep_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_async_sleep_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceAsyncSleep()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task<ulong> ReturnAfterMs(ulong x, ulong ms)
         {
-            return Interop.service_async_sleep_return_after_ms(_context, x, ms);
+            try { return Interop.service_async_sleep_return_after_ms(_context, x, ms); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncSleep FromHandle(IntPtr handle)
+        {
+            var self = new ServiceAsyncSleep();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_async_sleep_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceAsyncSleep service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12479,40 +13167,100 @@ namespace My.Company
         public static ServiceAsyncVecString New()
         {
             var self = new ServiceAsyncVecString();
-            self._context = Interop.service_async_vec_string_new().AsOk();
+            var result = Interop.service_async_vec_string_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncVecString service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncVecString service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_async_vec_string_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceAsyncVecString();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_vec_string_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_async_vec_string_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceAsyncVecString()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task<Utf8String> HandleString(Utf8String s)
         {
-            return Interop.service_async_vec_string_handle_string(_context, s);
+            try { return Interop.service_async_vec_string_handle_string(_context, s); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task<VecUtf8String> HandleVecString(VecUtf8String s)
         {
-            return Interop.service_async_vec_string_handle_vec_string(_context, s);
+            try { return Interop.service_async_vec_string_handle_vec_string(_context, s); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task<UseString> HandleNestedString(Utf8String s)
         {
-            return Interop.service_async_vec_string_handle_nested_string(_context, s);
+            try { return Interop.service_async_vec_string_handle_nested_string(_context, s); }
+            finally { GC.KeepAlive(this); }
         }
 
-        public IntPtr Context => _context;
-    }
-
-
-    public partial class ServiceAsyncResult : IDisposable
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncVecString FromHandle(IntPtr handle)
+        {
+            var self = new ServiceAsyncVecString();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_async_vec_string_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceAsyncVecString service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+    public partial class ServiceAsyncResult : IDisposable
     {
         private IntPtr _context;
 
@@ -12522,27 +13270,86 @@ namespace My.Company
         public static ServiceAsyncResult New()
         {
             var self = new ServiceAsyncResult();
-            self._context = Interop.service_async_result_new().AsOk();
+            var result = Interop.service_async_result_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncResult service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncResult service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_async_result_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceAsyncResult();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_result_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_async_result_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceAsyncResult()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task Success()
         {
-            return Interop.service_async_result_success(_context);
+            try { return Interop.service_async_result_success(_context); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task Fail()
         {
-            return Interop.service_async_result_fail(_context);
+            try { return Interop.service_async_result_fail(_context); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncResult FromHandle(IntPtr handle)
+        {
+            var self = new ServiceAsyncResult();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_async_result_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceAsyncResult service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12559,21 +13366,79 @@ namespace My.Company
         public static ServiceAsyncStructs New()
         {
             var self = new ServiceAsyncStructs();
-            self._context = Interop.service_async_structs_new().AsOk();
+            var result = Interop.service_async_structs_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncStructs service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceAsyncStructs service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_async_structs_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceAsyncStructs();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_async_structs_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_async_structs_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceAsyncStructs()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Task<NestedArray> ProcessStruct(NestedArray x)
         {
-            return Interop.service_async_structs_process_struct(_context, x);
+            try { return Interop.service_async_structs_process_struct(_context, x); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceAsyncStructs FromHandle(IntPtr handle)
+        {
+            var self = new ServiceAsyncStructs();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_async_structs_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceAsyncStructs service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12590,15 +13455,72 @@ namespace My.Company
         public static ServiceBasic New()
         {
             var self = new ServiceBasic();
-            self._context = Interop.service_basic_new().AsOk();
+            var result = Interop.service_basic_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceBasic service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceBasic service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_basic_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceBasic();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_basic_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_basic_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceBasic()
+        {
+            Dispose(false);
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceBasic FromHandle(IntPtr handle)
+        {
+            var self = new ServiceBasic();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_basic_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceBasic service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12615,15 +13537,72 @@ namespace My.Company
         public static ServiceMain New(uint x)
         {
             var self = new ServiceMain();
-            self._context = Interop.service_main_new(x).AsOk();
+            var result = Interop.service_main_new(x);
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(uint x, out ServiceMain service) => TryNew(x, out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(uint x, out ServiceMain service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_main_new(x);
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceMain();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_main_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_main_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceMain()
+        {
+            Dispose(false);
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMain FromHandle(IntPtr handle)
+        {
+            var self = new ServiceMain();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_main_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceMain service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12640,21 +13619,79 @@ namespace My.Company
         public static ServiceDependent FromMain(IntPtr main)
         {
             var self = new ServiceDependent();
-            self._context = Interop.service_dependent_from_main(main).AsOk();
+            var result = Interop.service_dependent_from_main(main);
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `FromMain` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromMain(IntPtr main, out ServiceDependent service) => TryFromMain(main, out service, out _);
+
+        /// Like `FromMain` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromMain(IntPtr main, out ServiceDependent service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_dependent_from_main(main);
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceDependent();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_dependent_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_dependent_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceDependent()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public uint Get()
         {
-            return Interop.service_dependent_get(_context);
+            try { return Interop.service_dependent_get(_context); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceDependent FromHandle(IntPtr handle)
+        {
+            var self = new ServiceDependent();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_dependent_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceDependent service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12671,45 +13708,107 @@ namespace My.Company
         public static ServiceResult New()
         {
             var self = new ServiceResult();
-            self._context = Interop.service_result_new().AsOk();
+            var result = Interop.service_result_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceResult service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceResult service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_result_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceResult();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_result_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_result_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceResult()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Test()
         {
-            Interop.service_result_test(_context).AsOk();
+            try { Interop.service_result_test(_context).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public uint ResultU32()
         {
-            return Interop.service_result_result_u32(_context).AsOk();
+            try { return Interop.service_result_result_u32(_context).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public Utf8String ResultString()
         {
-            return Interop.service_result_result_string(_context).AsOk();
+            try { return Interop.service_result_result_string(_context).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public OptionEnumPayload ResultOptionEnum()
         {
-            return Interop.service_result_result_option_enum(_context).AsOk();
+            try { return Interop.service_result_result_option_enum(_context).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public uint ResultSlice(SliceU32 slice, ulong i)
         {
-            return Interop.service_result_result_slice(_context, slice, i).AsOk();
+            try { return Interop.service_result_result_slice(_context, slice, i).AsOk(); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceResult FromHandle(IntPtr handle)
+        {
+            var self = new ServiceResult();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_result_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceResult service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12727,15 +13826,52 @@ namespace My.Company
         public static ServiceOnPanic New()
         {
             var self = new ServiceOnPanic();
-            self._context = Interop.service_on_panic_new().AsOk();
+            var result = Interop.service_on_panic_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceOnPanic service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceOnPanic service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_on_panic_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceOnPanic();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_on_panic_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_on_panic_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceOnPanic()
+        {
+            Dispose(false);
         }
 
         /// Methods returning a Result<(), _> are the default and do not
@@ -12743,14 +13879,16 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void ReturnResult(uint anon1)
         {
-            Interop.service_on_panic_return_result(_context, anon1).AsOk();
+            try { Interop.service_on_panic_return_result(_context, anon1).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         /// Methods returning a value need an `on_panic` annotation.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public uint ReturnDefaultValue(uint x)
         {
-            return Interop.service_on_panic_return_default_value(_context, x);
+            try { return Interop.service_on_panic_return_default_value(_context, x); }
+            finally { GC.KeepAlive(this); }
         }
 
         /// This function has no panic safeguards. It will be a bit faster to
@@ -12758,7 +13896,28 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public IntPtr ReturnUbOnPanic()
         {
-            return Interop.service_on_panic_return_ub_on_panic(_context);
+            try { return Interop.service_on_panic_return_ub_on_panic(_context); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceOnPanic FromHandle(IntPtr handle)
+        {
+            var self = new ServiceOnPanic();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_on_panic_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceOnPanic service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12776,63 +13935,128 @@ namespace My.Company
         public static ServiceCallbacks New()
         {
             var self = new ServiceCallbacks();
-            self._context = Interop.service_callbacks_new().AsOk();
+            var result = Interop.service_callbacks_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceCallbacks service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceCallbacks service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_callbacks_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceCallbacks();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_callbacks_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_callbacks_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceCallbacks()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackSimple(MyCallback callback)
         {
-            Interop.service_callbacks_callback_simple(_context, callback).AsOk();
+            try { Interop.service_callbacks_callback_simple(_context, callback).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackSimple(MyCallbackDelegate callback)
         {
-            Interop.service_callbacks_callback_simple(_context, callback).AsOk();
+            try { Interop.service_callbacks_callback_simple(_context, callback).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackFfiReturn(SumDelegateReturn callback)
         {
-            Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk();
+            try { Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackFfiReturn(SumDelegateReturnDelegate callback)
         {
-            Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk();
+            try { Interop.service_callbacks_callback_ffi_return(_context, callback).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackWithSlice(SumDelegateReturn callback, SliceI32 input)
         {
-            Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk();
+            try { Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackWithSlice(SumDelegateReturnDelegate callback, SliceI32 input)
         {
-            Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk();
+            try { Interop.service_callbacks_callback_with_slice(_context, callback, input).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void SetDelegateTable(CallbackTable table)
         {
-            Interop.service_callbacks_set_delegate_table(_context, table);
+            try { Interop.service_callbacks_set_delegate_table(_context, table); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void InvokeDelegates()
         {
-            Interop.service_callbacks_invoke_delegates(_context).AsOk();
+            try { Interop.service_callbacks_invoke_delegates(_context).AsOk(); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceCallbacks FromHandle(IntPtr handle)
+        {
+            var self = new ServiceCallbacks();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_callbacks_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceCallbacks service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12849,15 +14073,72 @@ namespace My.Company
         public static ServiceIgnoringMethods New()
         {
             var self = new ServiceIgnoringMethods();
-            self._context = Interop.service_ignoring_methods_new().AsOk();
+            var result = Interop.service_ignoring_methods_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceIgnoringMethods service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceIgnoringMethods service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_ignoring_methods_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceIgnoringMethods();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_ignoring_methods_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_ignoring_methods_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceIgnoringMethods()
+        {
+            Dispose(false);
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceIgnoringMethods FromHandle(IntPtr handle)
+        {
+            var self = new ServiceIgnoringMethods();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_ignoring_methods_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceIgnoringMethods service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12875,39 +14156,165 @@ namespace My.Company
         public static ServiceMultipleCtors NewWith(uint some_value)
         {
             var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_with(some_value).AsOk();
+            var result = Interop.service_multiple_ctors_new_with(some_value);
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `NewWith` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewWith(uint some_value, out ServiceMultipleCtors service) => TryNewWith(some_value, out service, out _);
+
+        /// Like `NewWith` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewWith(uint some_value, out ServiceMultipleCtors service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_multiple_ctors_new_with(some_value);
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceMultipleCtors();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static ServiceMultipleCtors NewWithout()
         {
             var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_without().AsOk();
+            var result = Interop.service_multiple_ctors_new_without();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `NewWithout` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewWithout(out ServiceMultipleCtors service) => TryNewWithout(out service, out _);
+
+        /// Like `NewWithout` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewWithout(out ServiceMultipleCtors service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_multiple_ctors_new_without();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceMultipleCtors();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static ServiceMultipleCtors NewWithString([MarshalAs(UnmanagedType.LPStr)] string anon0)
         {
             var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_with_string(anon0).AsOk();
+            var result = Interop.service_multiple_ctors_new_with_string(anon0);
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `NewWithString` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewWithString([MarshalAs(UnmanagedType.LPStr)] string anon0, out ServiceMultipleCtors service) => TryNewWithString(anon0, out service, out _);
+
+        /// Like `NewWithString` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewWithString([MarshalAs(UnmanagedType.LPStr)] string anon0, out ServiceMultipleCtors service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_multiple_ctors_new_with_string(anon0);
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceMultipleCtors();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static ServiceMultipleCtors NewFailing(byte some_value)
         {
             var self = new ServiceMultipleCtors();
-            self._context = Interop.service_multiple_ctors_new_failing(some_value).AsOk();
+            var result = Interop.service_multiple_ctors_new_failing(some_value);
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `NewFailing` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewFailing(byte some_value, out ServiceMultipleCtors service) => TryNewFailing(some_value, out service, out _);
+
+        /// Like `NewFailing` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewFailing(byte some_value, out ServiceMultipleCtors service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_multiple_ctors_new_failing(some_value);
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceMultipleCtors();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_multiple_ctors_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_multiple_ctors_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceMultipleCtors()
+        {
+            Dispose(false);
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceMultipleCtors FromHandle(IntPtr handle)
+        {
+            var self = new ServiceMultipleCtors();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_multiple_ctors_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceMultipleCtors service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -12925,58 +14332,102 @@ namespace My.Company
         public static ServiceVariousSlices New()
         {
             var self = new ServiceVariousSlices();
-            self._context = Interop.service_various_slices_new().AsOk();
+            var result = Interop.service_various_slices_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceVariousSlices service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceVariousSlices service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_various_slices_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceVariousSlices();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_various_slices_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_various_slices_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceVariousSlices()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public byte MutSelf(SliceU8 slice)
         {
-            return Interop.service_various_slices_mut_self(_context, slice);
+            try { return Interop.service_various_slices_mut_self(_context, slice); }
+            finally { GC.KeepAlive(this); }
         }
 
         /// Single line.
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void MutSelfVoid(SliceBool slice)
         {
-            Interop.service_various_slices_mut_self_void(_context, slice);
+            try { Interop.service_various_slices_mut_self_void(_context, slice); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public byte MutSelfRef(ref byte x, ref byte y)
         {
-            return Interop.service_various_slices_mut_self_ref(_context, ref x, ref y);
+            try { return Interop.service_various_slices_mut_self_ref(_context, ref x, ref y); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public byte MutSelfRefSlice(ref byte x, ref byte y, SliceU8 slice)
         {
-            return Interop.service_various_slices_mut_self_ref_slice(_context, ref x, ref y, slice);
+            try { return Interop.service_various_slices_mut_self_ref_slice(_context, ref x, ref y, slice); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public byte MutSelfRefSliceLimited(ref byte x, ref byte y, SliceU8 slice, SliceU8 slice2)
         {
-            return Interop.service_various_slices_mut_self_ref_slice_limited(_context, ref x, ref y, slice, slice2);
+            try { return Interop.service_various_slices_mut_self_ref_slice_limited(_context, ref x, ref y, slice, slice2); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void MutSelfFfiError(SliceMutU8 slice)
         {
-            Interop.service_various_slices_mut_self_ffi_error(_context, slice).AsOk();
+            try { Interop.service_various_slices_mut_self_ffi_error(_context, slice).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void MutSelfNoError(SliceMutU8 slice)
         {
-            Interop.service_various_slices_mut_self_no_error(_context, slice).AsOk();
+            try { Interop.service_various_slices_mut_self_no_error(_context, slice).AsOk(); }
+            finally { GC.KeepAlive(this); }
         }
 
         /// Warning, you _must_ discard the returned slice object before calling into this service
@@ -12984,7 +14435,8 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public SliceU32 ReturnSlice()
         {
-            return Interop.service_various_slices_return_slice(_context);
+            try { return Interop.service_various_slices_return_slice(_context); }
+            finally { GC.KeepAlive(this); }
         }
 
         /// Warning, you _must_ discard the returned slice object before calling into this service
@@ -12992,7 +14444,28 @@ namespace My.Company
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public SliceMutU32 ReturnSliceMut()
         {
-            return Interop.service_various_slices_return_slice_mut(_context);
+            try { return Interop.service_various_slices_return_slice_mut(_context); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceVariousSlices FromHandle(IntPtr handle)
+        {
+            var self = new ServiceVariousSlices();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_various_slices_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceVariousSlices service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -13010,47 +14483,131 @@ namespace My.Company
         public static ServiceStrings New()
         {
             var self = new ServiceStrings();
-            self._context = Interop.service_strings_new().AsOk();
+            var result = Interop.service_strings_new();
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceStrings service) => TryNew(out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(out ServiceStrings service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_strings_new();
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceStrings();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static ServiceStrings NewString(Utf8String x)
         {
             var self = new ServiceStrings();
-            self._context = Interop.service_strings_new_string(x).AsOk();
+            var result = Interop.service_strings_new_string(x);
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
             return self;
         }
 
+        /// Like `NewString` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewString(Utf8String x, out ServiceStrings service) => TryNewString(x, out service, out _);
+
+        /// Like `NewString` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNewString(Utf8String x, out ServiceStrings service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_strings_new_string(x);
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceStrings();
+            service._context = result.AsOk();
+            return true;
+        }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void Dispose()
         {
-            Interop.service_strings_destroy(_context).AsOk();
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_strings_destroy(_context);
             _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceStrings()
+        {
+            Dispose(false);
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void PassCstr([MarshalAs(UnmanagedType.LPStr)] string anon1)
         {
-            Interop.service_strings_pass_cstr(_context, anon1);
+            try { Interop.service_strings_pass_cstr(_context, anon1); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public IntPtr ReturnCstr()
         {
-            return Interop.service_strings_return_cstr(_context);
+            try { return Interop.service_strings_return_cstr(_context); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackString(Utf8String s, StringCallback cb)
         {
-            Interop.service_strings_callback_string(_context, s, cb);
+            try { Interop.service_strings_callback_string(_context, s, cb); }
+            finally { GC.KeepAlive(this); }
         }
 
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public void CallbackString(Utf8String s, StringCallbackDelegate cb)
         {
-            Interop.service_strings_callback_string(_context, s, cb);
+            try { Interop.service_strings_callback_string(_context, s, cb); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceStrings FromHandle(IntPtr handle)
+        {
+            var self = new ServiceStrings();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_strings_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceStrings service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
         }
 
         public IntPtr Context => _context;
@@ -13064,6 +14621,16 @@ namespace My.Company
         {
         }
     }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -13169,15 +14736,30 @@ namespace My.Company
         {
             var rval = new Utf8String();
             var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
 
-            fixed (byte* p = utf8Bytes)
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
             }
 
             return rval;
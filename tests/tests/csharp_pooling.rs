@@ -0,0 +1,69 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi, ffi_function, ffi_type, function};
+use interoptopus_backend_csharp::{Interop, Pooling};
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type(poolable)]
+pub struct Message {
+    pub text: ffi::String,
+    pub id: u64,
+}
+
+#[ffi_type(poolable)]
+#[derive(Copy, Clone)]
+pub struct Blittable {
+    pub x: u32,
+}
+
+#[ffi_function]
+pub fn send_message(_message: Message, _blittable: Blittable) {}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(function!(send_message)).validate().build()
+}
+
+fn generate(pooling: Pooling) -> Result<String, Error> {
+    Ok(Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .pooling(pooling)
+        .build()?
+        .to_string()?)
+}
+
+#[test]
+fn pool_single_threaded() -> Result<(), Error> {
+    let generated = generate(Pooling::SingleThreaded)?;
+
+    assert!(generated.contains("public partial class MessagePool"));
+    assert!(generated.contains("readonly Stack<Message> _items = new();"));
+    assert!(generated.contains("var pool = System.Threading.Interlocked.Exchange(ref _pool, null);"));
+    assert!(generated.contains("pool?.Return(this);"));
+    assert!(!generated.contains("BlittablePool"));
+
+    validate_output!("tests", "csharp_pooling.cs", generated.as_str());
+
+    Ok(())
+}
+
+#[test]
+fn pool_thread_safe() -> Result<(), Error> {
+    let generated = generate(Pooling::ThreadSafe)?;
+
+    assert!(generated.contains("readonly System.Collections.Concurrent.ConcurrentBag<Message> _items = new();"));
+    assert!(generated.contains("if (!_items.TryTake(out var item)) { item = new Message(); }"));
+
+    Ok(())
+}
+
+#[test]
+fn pool_disabled() -> Result<(), Error> {
+    let generated = generate(Pooling::Disabled)?;
+
+    assert!(!generated.contains("MessagePool"));
+
+    Ok(())
+}
@@ -3,7 +3,7 @@
 // This file was automatically generated by Interoptopus.
 //
 // Library:      interoptopus_reference_project
-// Hash:         0xc4c42a56d8c2b9a7
+// Hash:         0xcbbcba9af28203c1
 // Namespace:    _common
 // Builder:      interoptopus_backend_csharp
 //
@@ -22,7 +22,6 @@ using System.Runtime.InteropServices;
 using System.Runtime.InteropServices.Marshalling;
 using System.Runtime.CompilerServices;
 using My.Company;
-using My.Company;
 using My.Company.Common;
 #pragma warning restore 0105
 
@@ -42,6 +41,16 @@ namespace My.Company.Common
 
 
 
+        public EnumDocumentedEnum AsEnum() => (EnumDocumentedEnum) _variant;
+        public static implicit operator EnumDocumentedEnum(EnumDocumented value) => value.AsEnum();
+
+        public enum EnumDocumentedEnum : uint
+        {
+            A = 0,
+            B = 1,
+            C = 2,
+        }
+
         [StructLayout(LayoutKind.Explicit)]
         public unsafe struct Unmanaged
         {
@@ -85,6 +94,13 @@ namespace My.Company.Common
         public void AsB() { if (_variant != 1) throw new InteropException(); }
         public void AsC() { if (_variant != 2) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsAOrElse(Action<EnumDocumented> cb) { if (_variant != 0) cb(this); }
+        public void AsBOrElse(Action<EnumDocumented> cb) { if (_variant != 1) cb(this); }
+        public void AsCOrElse(Action<EnumDocumented> cb) { if (_variant != 2) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -94,6 +110,15 @@ namespace My.Company.Common
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out EnumDocumented result)
+        {
+            if (string.Equals(value, "A", StringComparison.Ordinal)) { result = A; return true; }
+            if (string.Equals(value, "B", StringComparison.Ordinal)) { result = B; return true; }
+            if (string.Equals(value, "C", StringComparison.Ordinal)) { result = C; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(EnumDocumented), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -206,6 +231,15 @@ namespace My.Company.Common
         public void AsB() { if (_variant != 1) throw new InteropException(); }
         public nint AsC() { if (_variant != 2) { throw new InteropException(); } else { return _C; } }
 
+        #nullable enable
+        public nuint? AsAOrNull() => _variant == 0 ? _A : null;
+        public nint? AsCOrNull() => _variant == 2 ? _C : null;
+        #nullable disable
+
+        public nuint AsAOrElse(Func<EnumNum, nuint> cb) => _variant == 0 ? _A : cb(this);
+        public void AsBOrElse(Action<EnumNum> cb) { if (_variant != 1) cb(this); }
+        public nint AsCOrElse(Func<EnumNum, nint> cb) => _variant == 2 ? _C : cb(this);
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -256,6 +290,14 @@ namespace My.Company.Common
     {
 
 
+        public EnumRenamedEnum AsEnum() => (EnumRenamedEnum) _variant;
+        public static implicit operator EnumRenamedEnum(EnumRenamed value) => value.AsEnum();
+
+        public enum EnumRenamedEnum : uint
+        {
+            X = 0,
+        }
+
         [StructLayout(LayoutKind.Explicit)]
         public unsafe struct Unmanaged
         {
@@ -293,6 +335,11 @@ namespace My.Company.Common
 
         public void AsX() { if (_variant != 0) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsXOrElse(Action<EnumRenamed> cb) { if (_variant != 0) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -300,6 +347,13 @@ namespace My.Company.Common
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out EnumRenamed result)
+        {
+            if (string.Equals(value, "X", StringComparison.Ordinal)) { result = X; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(EnumRenamed), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -341,6 +395,14 @@ namespace My.Company.Common
     {
 
 
+        public ErrorEnum AsEnum() => (ErrorEnum) _variant;
+        public static implicit operator ErrorEnum(Error value) => value.AsEnum();
+
+        public enum ErrorEnum : uint
+        {
+            Fail = 0,
+        }
+
         [StructLayout(LayoutKind.Explicit)]
         public unsafe struct Unmanaged
         {
@@ -378,6 +440,11 @@ namespace My.Company.Common
 
         public void AsFail() { if (_variant != 0) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsFailOrElse(Action<Error> cb) { if (_variant != 0) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -385,6 +452,13 @@ namespace My.Company.Common
             throw new InteropException();
         }
 
+        public static bool TryParse(string value, out Error result)
+        {
+            if (string.Equals(value, "Fail", StringComparison.Ordinal)) { result = Fail; return true; }
+            result = default;
+            return false;
+        }
+
         [CustomMarshaller(typeof(Error), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -420,6 +494,12 @@ namespace My.Company.Common
     {
         public double x;
         public double z;
+        public Vec(double _x, double _z)
+        {
+            x = _x;
+            z = _z;
+        }
+
     }
 
     [NativeMarshalling(typeof(MarshallerMeta))]
@@ -1161,6 +1241,7 @@ namespace My.Company.Common
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static SliceVec From(Vec[] managed)
         {
+            AssertElementLayout();
             var rval = new SliceVec();
             rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
             rval._data = rval._handle.AddrOfPinnedObject();
@@ -1193,6 +1274,22 @@ namespace My.Company.Common
         }
 
 
+        [System.Diagnostics.Conditional("DEBUG")]
+        static void AssertElementLayout()
+        {
+            var size = Unsafe.SizeOf<Vec>();
+            var align = (int) Marshal.OffsetOf<ElementAlignment>(nameof(ElementAlignment.element));
+            System.Diagnostics.Debug.Assert(size == 16, $"Size of 'Vec' is {size} bytes in C#, but 16 in Rust.");
+            System.Diagnostics.Debug.Assert(align == 8, $"Alignment of 'Vec' is {align} bytes in C#, but 8 in Rust.");
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        struct ElementAlignment
+        {
+            public byte padding;
+            public Vec element;
+        }
+
         [CustomMarshaller(typeof(SliceVec), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -1554,6 +1651,7 @@ namespace My.Company.Common
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public static SliceMutVec From(Vec[] managed)
         {
+            AssertElementLayout();
             var rval = new SliceMutVec();
             rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
             rval._data = rval._handle.AddrOfPinnedObject();
@@ -1586,6 +1684,22 @@ namespace My.Company.Common
         }
 
 
+        [System.Diagnostics.Conditional("DEBUG")]
+        static void AssertElementLayout()
+        {
+            var size = Unsafe.SizeOf<Vec>();
+            var align = (int) Marshal.OffsetOf<ElementAlignment>(nameof(ElementAlignment.element));
+            System.Diagnostics.Debug.Assert(size == 16, $"Size of 'Vec' is {size} bytes in C#, but 16 in Rust.");
+            System.Diagnostics.Debug.Assert(align == 8, $"Alignment of 'Vec' is {align} bytes in C#, but 8 in Rust.");
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        struct ElementAlignment
+        {
+            public byte padding;
+            public Vec element;
+        }
+
         [CustomMarshaller(typeof(SliceMutVec), MarshalMode.Default, typeof(Marshaller))]
         private struct MarshallerMeta { }
 
@@ -1705,6 +1819,13 @@ namespace My.Company.Common
         public OptionResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public OptionResultOptionUtf8StringError? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public OptionResultOptionUtf8StringError AsSomeOrElse(Func<OptionOptionResultOptionUtf8StringError, OptionResultOptionUtf8StringError> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionOptionResultOptionUtf8StringError> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -1813,6 +1934,13 @@ namespace My.Company.Common
         public ResultOptionUtf8StringError AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public ResultOptionUtf8StringError? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public ResultOptionUtf8StringError AsSomeOrElse(Func<OptionResultOptionUtf8StringError, ResultOptionUtf8StringError> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionResultOptionUtf8StringError> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -1921,6 +2049,13 @@ namespace My.Company.Common
         public Utf8String AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public Utf8String? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public Utf8String AsSomeOrElse(Func<OptionUtf8String, Utf8String> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionUtf8String> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2025,6 +2160,13 @@ namespace My.Company.Common
         public Vec AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
         public void AsNone() { if (_variant != 1) throw new InteropException(); }
 
+        #nullable enable
+        public Vec? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public Vec AsSomeOrElse(Func<OptionVec, Vec> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionVec> cb) { if (_variant != 1) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2137,6 +2279,15 @@ namespace My.Company.Common
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public void AsOkOrElse(Action<ResultError> cb) { if (_variant != 0) cb(this); }
+        public Error AsErrOrElse(Func<ResultError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2268,6 +2419,16 @@ namespace My.Company.Common
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public OptionUtf8String? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public OptionUtf8String AsOkOrElse(Func<ResultOptionUtf8StringError, OptionUtf8String> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultOptionUtf8StringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultOptionUtf8StringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultOptionUtf8StringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2395,6 +2556,16 @@ namespace My.Company.Common
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public uint? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public uint AsOkOrElse(Func<ResultU32Error, uint> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultU32Error, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultU32Error> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultU32Error> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2522,6 +2693,16 @@ namespace My.Company.Common
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public ulong? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public ulong AsOkOrElse(Func<ResultU64Error, ulong> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultU64Error, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultU64Error> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultU64Error> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2653,6 +2834,16 @@ namespace My.Company.Common
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public Utf8String? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public Utf8String AsOkOrElse(Func<ResultUtf8StringError, Utf8String> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultUtf8StringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultUtf8StringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultUtf8StringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2784,6 +2975,16 @@ namespace My.Company.Common
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        public VecUtf8String? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public VecUtf8String AsOkOrElse(Func<ResultVecUtf8StringError, VecUtf8String> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultVecUtf8StringError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultVecUtf8StringError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultVecUtf8StringError> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -2885,6 +3086,14 @@ namespace My.Company.Common
         public void AsPanic() { if (_variant != 2) throw new InteropException(); }
         public void AsNull() { if (_variant != 3) throw new InteropException(); }
 
+        #nullable enable
+        #nullable disable
+
+        public void AsOkOrElse(Action<ResultVoid> cb) { if (_variant != 0) cb(this); }
+        public void AsErrOrElse(Action<ResultVoid> cb) { if (_variant != 1) cb(this); }
+        public void AsPanicOrElse(Action<ResultVoid> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultVoid> cb) { if (_variant != 3) cb(this); }
+
         [MethodImpl(MethodImplOptions.AggressiveOptimization)]
         public override string ToString()
         {
@@ -3399,6 +3608,16 @@ namespace My.Company.Common
         {
         }
     }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
     [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
     public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
     public delegate void AsyncHelperDelegate(IntPtr data);
@@ -3504,15 +3723,30 @@ namespace My.Company.Common
         {
             var rval = new Utf8String();
             var source = s.AsSpan();
-            Span<byte> utf8Bytes = stackalloc byte[Encoding.UTF8.GetByteCount(source)];
-            var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
 
-            fixed (byte* p = utf8Bytes)
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
             {
-                InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
-                rval._ptr = native._ptr;
-                rval._len = native._len;
-                rval._capacity = native._capacity;
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
             }
 
             return rval;
@@ -0,0 +1,83 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi_function, ffi_type, function};
+use interoptopus_backend_csharp::Interop;
+use std::mem::MaybeUninit;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix4 {
+    pub m: [f32; 16],
+}
+
+#[ffi_function(return_via_out)]
+pub fn matrix_scale(x: f32) -> Matrix4 {
+    let mut m = [0.0; 16];
+    m[0] = x;
+    m[5] = x;
+    m[10] = x;
+    m[15] = 1.0;
+    Matrix4 { m }
+}
+
+#[ffi_function(return_via_out)]
+pub fn matrix_identity() -> Matrix4 {
+    matrix_scale(1.0)
+}
+
+unsafe extern "C" {
+    #[link_name = "matrix_scale"]
+    fn matrix_scale_native(x: f32, rval: *mut Matrix4);
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder()
+        .register(function!(matrix_scale))
+        .register(function!(matrix_identity))
+        .validate()
+        .build()
+}
+
+#[test]
+fn native_symbol_writes_out_param() {
+    let mut rval = MaybeUninit::<Matrix4>::uninit();
+    let rval = unsafe {
+        matrix_scale_native(2.0, rval.as_mut_ptr());
+        rval.assume_init()
+    };
+
+    assert_eq!(rval, matrix_scale(2.0));
+}
+
+#[test]
+fn inventory_reflects_native_contract() {
+    let inventory = ffi_inventory();
+    let function = inventory.functions().iter().find(|x| x.name() == "matrix_scale").unwrap();
+
+    assert!(function.returns_via_out());
+    assert!(function.signature().rval().is_void());
+    assert_eq!(function.signature().params().last().unwrap().name(), "rval");
+    assert_eq!(function.out_return_type().and_then(|x| x.as_composite_type()).map(|x| x.rust_name()), Some("Matrix4"));
+}
+
+#[test]
+fn csharp_uses_out_param() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("public static partial void matrix_scale(float x, out Matrix4 rval);"));
+    assert!(generated.contains("public static partial void matrix_identity(out Matrix4 rval);"));
+    assert!(generated.contains("public static Matrix4 matrix_scale(float x)"));
+    assert!(generated.contains("matrix_scale(x, out var rval);"));
+    assert!(generated.contains("public static Matrix4 matrix_identity()"));
+
+    validate_output!("tests", "csharp_return_via_out.cs", generated.as_str());
+
+    Ok(())
+}
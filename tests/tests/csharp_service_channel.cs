@@ -0,0 +1,1134 @@
+// <auto-generated>
+//
+// This file was automatically generated by Interoptopus.
+//
+// Library:      library
+// Hash:         0x1a835e5661c855b8
+// Namespace:    
+// Builder:      interoptopus_backend_csharp
+//
+// Do not edit this file manually.
+//
+// </auto-generated>
+
+
+#pragma warning disable 0105
+using System;
+using System.Text;
+using System.Threading.Tasks;
+using System.Collections;
+using System.Collections.Generic;
+using System.Runtime.InteropServices;
+using System.Runtime.InteropServices.Marshalling;
+using System.Runtime.CompilerServices;
+using My.Company;
+using My.Company.Common;
+#pragma warning restore 0105
+
+namespace My.Company
+{
+    public static partial class Interop
+    {
+        public const string NativeLib = "library";
+
+        static Interop()
+        {
+        }
+
+        internal static AsyncTrampolineResultOptionU32Error _trampolineResultOptionU32Error = new();
+
+
+        /// Destroys the given instance.
+        ///
+        /// # Safety
+        ///
+        /// The passed parameter MUST have been created with the corresponding init function;
+        /// passing any other value results in undefined behavior.
+        [LibraryImport(NativeLib, EntryPoint = "service_channel_destroy")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceChannelError service_channel_destroy(IntPtr _context);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "service_channel_new")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultConstPtrServiceChannelError service_channel_new(uint count);
+
+
+        /// Waits for the next item, or `None` once all items were sent.
+        [LibraryImport(NativeLib, EntryPoint = "service_channel_recv_async")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial ResultError service_channel_recv_async(IntPtr _context, AsyncCallbackCommonNative _async_callback);
+
+        /// Waits for the next item, or `None` once all items were sent.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Task<OptionU32> service_channel_recv_async(IntPtr _context)
+        {
+            var (_cb, _cs) = _trampolineResultOptionU32Error.NewCall();
+            try
+            {
+                service_channel_recv_async(_context, _cb).AsOk();
+                return _cs;
+            }
+            finally
+            {
+            }
+            return _cs;
+        }
+
+    }
+
+    public partial struct Error
+    {
+        uint _variant;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Error 
+    {
+
+
+        public ErrorEnum AsEnum() => (ErrorEnum) _variant;
+        public static implicit operator ErrorEnum(Error value) => value.AsEnum();
+
+        public enum ErrorEnum : uint
+        {
+            Fail = 0,
+        }
+
+        [StructLayout(LayoutKind.Explicit)]
+        public unsafe struct Unmanaged
+        {
+            [FieldOffset(0)]
+            internal uint _variant;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal Error ToManaged()
+            {
+                var _managed = new Error();
+                _managed._variant = _variant;
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            return _unmanaged;
+        }
+
+        public static Error Fail => new() { _variant = 0 };
+
+        public bool IsFail => _variant == 0;
+
+        public void AsFail() { if (_variant != 0) throw new InteropException(); }
+
+        #nullable enable
+        #nullable disable
+
+        public void AsFailOrElse(Action<Error> cb) { if (_variant != 0) cb(this); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Fail";
+            throw new InteropException();
+        }
+
+        public static bool TryParse(string value, out Error result)
+        {
+            if (string.Equals(value, "Fail", StringComparison.Ordinal)) { result = Fail; return true; }
+            result = default;
+            return false;
+        }
+
+        [CustomMarshaller(typeof(Error), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private Error _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Error managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Error managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Error ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    ///Option that contains Some(value) or None.
+    public partial struct OptionU32
+    {
+        uint _variant;
+        uint _Some;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionU32 
+    {
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct UnmanagedSome
+        {
+            internal uint _variant;
+            internal uint _Some;
+        }
+
+
+
+        [StructLayout(LayoutKind.Explicit)]
+        public unsafe struct Unmanaged
+        {
+            [FieldOffset(0)]
+            internal uint _variant;
+
+            [FieldOffset(0)]
+            internal UnmanagedSome _Some;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal OptionU32 ToManaged()
+            {
+                var _managed = new OptionU32();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Some = _Some._Some;
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Some._Some = _Some;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Some._Some = _Some;
+            return _unmanaged;
+        }
+
+        public static OptionU32 Some(uint value) => new() { _variant = 0, _Some = value };
+        public static OptionU32 None => new() { _variant = 1 };
+
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
+
+        public uint AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
+
+        #nullable enable
+        public uint? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public uint AsSomeOrElse(Func<OptionU32, uint> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionU32> cb) { if (_variant != 1) cb(this); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(OptionU32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private OptionU32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(OptionU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(OptionU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public OptionU32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    ///Result that contains value or an error.
+    public partial struct ResultConstPtrServiceChannelError
+    {
+        uint _variant;
+        IntPtr _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultConstPtrServiceChannelError 
+    {
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct UnmanagedOk
+        {
+            internal uint _variant;
+            internal IntPtr _Ok;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct UnmanagedErr
+        {
+            internal uint _variant;
+            internal Error.Unmanaged _Err;
+        }
+
+
+
+
+        [StructLayout(LayoutKind.Explicit)]
+        public unsafe struct Unmanaged
+        {
+            [FieldOffset(0)]
+            internal uint _variant;
+
+            [FieldOffset(0)]
+            internal UnmanagedOk _Ok;
+
+            [FieldOffset(0)]
+            internal UnmanagedErr _Err;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultConstPtrServiceChannelError ToManaged()
+            {
+                var _managed = new ResultConstPtrServiceChannelError();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _Ok._Ok;
+                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
+            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Ok._Ok = _Ok;
+            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultConstPtrServiceChannelError Ok(IntPtr value) => new() { _variant = 0, _Ok = value };
+        public static ResultConstPtrServiceChannelError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultConstPtrServiceChannelError Panic => new() { _variant = 2 };
+        public static ResultConstPtrServiceChannelError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        public IntPtr AsOk() { if (_variant != 0) { throw new InteropException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        #nullable enable
+        public IntPtr? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public IntPtr AsOkOrElse(Func<ResultConstPtrServiceChannelError, IntPtr> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultConstPtrServiceChannelError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultConstPtrServiceChannelError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultConstPtrServiceChannelError> cb) { if (_variant != 3) cb(this); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultConstPtrServiceChannelError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultConstPtrServiceChannelError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultConstPtrServiceChannelError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultConstPtrServiceChannelError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultConstPtrServiceChannelError ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    ///Result that contains value or an error.
+    public partial struct ResultError
+    {
+        uint _variant;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultError 
+    {
+
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct UnmanagedErr
+        {
+            internal uint _variant;
+            internal Error.Unmanaged _Err;
+        }
+
+
+
+
+        [StructLayout(LayoutKind.Explicit)]
+        public unsafe struct Unmanaged
+        {
+            [FieldOffset(0)]
+            internal uint _variant;
+
+            [FieldOffset(0)]
+            internal UnmanagedErr _Err;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultError ToManaged()
+            {
+                var _managed = new ResultError();
+                _managed._variant = _variant;
+                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultError Ok => new() { _variant = 0 };
+        public static ResultError Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultError Panic => new() { _variant = 2 };
+        public static ResultError Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        public void AsOk() { if (_variant != 0) throw new InteropException(); }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        #nullable enable
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public void AsOkOrElse(Action<ResultError> cb) { if (_variant != 0) cb(this); }
+        public Error AsErrOrElse(Func<ResultError, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultError> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultError> cb) { if (_variant != 3) cb(this); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultError), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultError _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultError managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultError ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    ///Result that contains value or an error.
+    public partial struct ResultOptionU32Error
+    {
+        uint _variant;
+        OptionU32 _Ok;
+        Error _Err;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct ResultOptionU32Error 
+    {
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct UnmanagedOk
+        {
+            internal uint _variant;
+            internal OptionU32.Unmanaged _Ok;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct UnmanagedErr
+        {
+            internal uint _variant;
+            internal Error.Unmanaged _Err;
+        }
+
+
+
+
+        [StructLayout(LayoutKind.Explicit)]
+        public unsafe struct Unmanaged
+        {
+            [FieldOffset(0)]
+            internal uint _variant;
+
+            [FieldOffset(0)]
+            internal UnmanagedOk _Ok;
+
+            [FieldOffset(0)]
+            internal UnmanagedErr _Err;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal ResultOptionU32Error ToManaged()
+            {
+                var _managed = new ResultOptionU32Error();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Ok = _Ok._Ok.ToManaged();
+                if (_variant == 1) _managed._Err = _Err._Err.ToManaged();
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Ok._Ok = _Ok.ToUnmanaged();
+            if (_variant == 1) _unmanaged._Err._Err = _Err.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        public static ResultOptionU32Error Ok(OptionU32 value) => new() { _variant = 0, _Ok = value };
+        public static ResultOptionU32Error Err(Error value) => new() { _variant = 1, _Err = value };
+        public static ResultOptionU32Error Panic => new() { _variant = 2 };
+        public static ResultOptionU32Error Null => new() { _variant = 3 };
+
+        public bool IsOk => _variant == 0;
+        public bool IsErr => _variant == 1;
+        public bool IsPanic => _variant == 2;
+        public bool IsNull => _variant == 3;
+
+        public OptionU32 AsOk() { if (_variant != 0) { throw new InteropException(); } else { return _Ok; } }
+        public Error AsErr() { if (_variant != 1) { throw new InteropException(); } else { return _Err; } }
+        public void AsPanic() { if (_variant != 2) throw new InteropException(); }
+        public void AsNull() { if (_variant != 3) throw new InteropException(); }
+
+        #nullable enable
+        public OptionU32? AsOkOrNull() => _variant == 0 ? _Ok : null;
+        public Error? AsErrOrNull() => _variant == 1 ? _Err : null;
+        #nullable disable
+
+        public OptionU32 AsOkOrElse(Func<ResultOptionU32Error, OptionU32> cb) => _variant == 0 ? _Ok : cb(this);
+        public Error AsErrOrElse(Func<ResultOptionU32Error, Error> cb) => _variant == 1 ? _Err : cb(this);
+        public void AsPanicOrElse(Action<ResultOptionU32Error> cb) { if (_variant != 2) cb(this); }
+        public void AsNullOrElse(Action<ResultOptionU32Error> cb) { if (_variant != 3) cb(this); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Ok(...)";
+            if (_variant == 1) return $"Err({AsErr().ToString()})";
+            if (_variant == 2) return "Panic";
+            if (_variant == 3) return "Null";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(ResultOptionU32Error), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private ResultOptionU32Error _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(ResultOptionU32Error managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(ResultOptionU32Error managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public ResultOptionU32Error ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public class AsyncTrampolineResultOptionU32Error
+    {
+        private static ulong Id = 0;
+        private static Dictionary<ulong, TaskCompletionSource<OptionU32>> InFlight = new(1024);
+        private AsyncCallbackCommon _delegate;
+        private IntPtr _callback_ptr;
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal AsyncTrampolineResultOptionU32Error()
+        {
+            _delegate = Call;
+            _callback_ptr = Marshal.GetFunctionPointerForDelegate(_delegate);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        private static void Call(IntPtr data, IntPtr csPtr)
+        {
+            TaskCompletionSource<OptionU32> tcs;
+            
+            lock (InFlight) { InFlight.Remove((ulong) csPtr, out tcs); }
+            
+            var unmanaged = Marshal.PtrToStructure<ResultOptionU32Error.Unmanaged>(data);
+            var managed = unmanaged.ToManaged();
+            if (managed.IsOk) { tcs.SetResult(managed.AsOk()); }
+            else { tcs.SetException(new InteropException()); }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal (AsyncCallbackCommonNative, Task<OptionU32>) NewCall()
+        {
+            var tcs = new TaskCompletionSource<OptionU32>();
+            var id = Id++;
+            
+            lock (InFlight) { InFlight.TryAdd(id, tcs); }
+            
+            var ac = new AsyncCallbackCommonNative {
+                _ptr = _callback_ptr,
+                _ts = (IntPtr) id,
+            };
+
+            return (ac, tcs.Task);
+        }
+    }
+
+
+    public partial class ServiceChannel : IDisposable
+    {
+        private IntPtr _context;
+
+        private ServiceChannel() {}
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceChannel New(uint count)
+        {
+            var self = new ServiceChannel();
+            var result = Interop.service_channel_new(count);
+            if (!result.IsOk) throw new InteropException<Error>(result.AsErr());
+            self._context = result.AsOk();
+            return self;
+        }
+
+        /// Like `New` but returns `false` instead of throwing if the native constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(uint count, out ServiceChannel service) => TryNew(count, out service, out _);
+
+        /// Like `New` but returns `false` and the native error instead of throwing if the constructor fails.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryNew(uint count, out ServiceChannel service, out Error error)
+        {
+            service = null;
+            error = default;
+            var result = Interop.service_channel_new(count);
+            if (!result.IsOk)
+            {
+                error = result.AsErr();
+                return false;
+            }
+            service = new ServiceChannel();
+            service._context = result.AsOk();
+            return true;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        protected virtual void Dispose(bool disposing)
+        {
+            if (_context == IntPtr.Zero) return;
+            var result = Interop.service_channel_destroy(_context);
+            _context = IntPtr.Zero;
+            // Finalizers must not throw, so errors are only surfaced on explicit disposal.
+            if (disposing) result.AsOk();
+        }
+
+        ~ServiceChannel()
+        {
+            Dispose(false);
+        }
+
+        /// Waits for the next item, or `None` once all items were sent.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Task<OptionU32> RecvAsync()
+        {
+            try { return Interop.service_channel_recv_async(_context); }
+            finally { GC.KeepAlive(this); }
+        }
+
+        /// Receives items until the channel is closed. Cancelling disposes this service.
+        public async IAsyncEnumerable<uint> ReadAllAsync([EnumeratorCancellation] System.Threading.CancellationToken cancellationToken = default)
+        {
+            while (true)
+            {
+                OptionU32 item;
+                try { item = await RecvAsync().WaitAsync(cancellationToken); }
+                catch (OperationCanceledException) when (cancellationToken.IsCancellationRequested) { Dispose(); throw; }
+                if (item.IsNone) yield break;
+                yield return item.AsSome();
+            }
+        }
+
+        /// Wraps an existing native handle, taking ownership of it. The handle is not validated.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static ServiceChannel FromHandle(IntPtr handle)
+        {
+            var self = new ServiceChannel();
+            self._context = handle;
+            return self;
+        }
+
+        /// Wraps an existing native handle, taking ownership of it, if the handle is valid.
+        /// Without a native `service_channel_is_valid` function this can only check for null handles.
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static bool TryFromHandle(IntPtr handle, out ServiceChannel service)
+        {
+            service = null;
+            if (handle == IntPtr.Zero) return false;
+            service = FromHandle(handle);
+            return true;
+        }
+
+        public IntPtr Context => _context;
+    }
+
+
+
+    public class InteropException : Exception
+    {
+        public InteropException() : base()
+        {
+        }
+    }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
+    public delegate void AsyncHelperDelegate(IntPtr data);
+
+    public partial struct AsyncHelper
+    {
+        private AsyncHelperDelegate _managed;
+        private AsyncHelperNative _native;
+        private IntPtr _ptr;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct AsyncHelper : IDisposable
+    {
+        public AsyncHelper() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public AsyncHelper(AsyncHelperDelegate managed)
+        {
+            _managed = managed;
+            _native = Call;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        void Call(IntPtr data, IntPtr _)
+        {
+            _managed(data);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            Marshal.FreeHGlobal(_ptr);
+            _ptr = IntPtr.Zero;
+        }
+
+        [CustomMarshaller(typeof(AsyncHelper), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr Callback;
+            internal IntPtr Data;
+        }
+
+        public ref struct Marshaller
+        {
+            private AsyncHelper _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(AsyncHelper managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged()
+            {
+                _unmanaged = new Unmanaged();
+                _unmanaged.Callback = _managed._ptr;
+                _unmanaged.Data = IntPtr.Zero;
+                return _unmanaged;
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public AsyncHelper ToManaged()
+            {
+                _managed = new AsyncHelper();
+                _managed._ptr = _unmanaged.Callback;
+                return _managed;
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() { }
+        }
+    }
+
+    public delegate void AsyncCallbackCommon(IntPtr data, IntPtr callback_data);
+
+    [StructLayout(LayoutKind.Sequential)]
+    public partial struct AsyncCallbackCommonNative
+    {
+        internal IntPtr _ptr;
+        internal IntPtr _ts;
+    }
+    public partial class Utf8String
+    {
+        IntPtr _ptr;
+        ulong _len;
+        ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class Utf8String : IDisposable
+    {
+        private Utf8String() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String From(string s)
+        {
+            var rval = new Utf8String();
+            var source = s.AsSpan();
+
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
+            {
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
+            }
+
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String Empty()
+        {
+            InteropHelper.interoptopus_string_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+
+        public unsafe string String
+        {
+            get
+            {
+                var span = new ReadOnlySpan<byte>((byte*)_ptr, (int)_len);
+                var s = Encoding.UTF8.GetString(span);
+                return s;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public string IntoString()
+        {
+            var rval = String;
+            Dispose();
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_string_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String Clone()
+        {
+            var _new = new Unmanaged();
+            var _this = AsUnmanaged();
+            InteropHelper.interoptopus_string_clone(ref _this, ref _new);
+            return _new.IntoManaged();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) { throw new Exception(); }
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            _ptr = IntPtr.Zero;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public IntPtr _ptr;
+            public ulong _len;
+            public ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Utf8String IntoManaged()
+            {
+                var _managed = new Utf8String();
+                _managed._ptr = _ptr;
+                _managed._len = _len;
+                _managed._capacity = _capacity;
+                return _managed;
+            }
+
+        }
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_create")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_destroy")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_destroy(Unmanaged utf8);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_clone")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_clone(ref Unmanaged orig, ref Unmanaged cloned);
+        }
+
+        [CustomMarshaller(typeof(Utf8String), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private Utf8String _managed; // Used when converting managed -> unmanaged
+            private Unmanaged _unmanaged; // Used when converting unmanaged -> managed
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Utf8String managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Utf8String managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public unsafe Unmanaged ToUnmanaged()
+            {
+                return _managed.IntoUnmanaged();
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public unsafe Utf8String ToManaged()
+            {
+                return _unmanaged.IntoManaged();
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() { }
+        }
+    }
+
+    public static class StringExtensions
+    {
+        public static Utf8String Utf8(this string s) { return Utf8String.From(s); }
+    }
+}
@@ -0,0 +1,98 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::pattern::asynk::{AsyncRuntime, AsyncSelf};
+use interoptopus::{ffi, ffi_service, ffi_type, pattern};
+use interoptopus_backend_csharp::Interop;
+use interoptopus_reference_project::patterns::result::Error as FFIError;
+use std::future::Future;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+use tokio::runtime::{Builder, Runtime};
+use tokio::sync::mpsc::{channel, Receiver};
+use tokio::sync::Mutex;
+
+#[ffi_type(opaque)]
+pub struct ServiceChannel {
+    runtime: Runtime,
+    receiver: Mutex<Receiver<u32>>,
+}
+
+#[ffi_service]
+impl ServiceChannel {
+    pub fn new(count: u32) -> ffi::Result<Self, FFIError> {
+        let Ok(runtime) = Builder::new_multi_thread().build() else {
+            return ffi::Err(FFIError::Fail);
+        };
+
+        let (sender, receiver) = channel(16);
+
+        // The channel is closed once the sender is dropped after the last item.
+        runtime.spawn(async move {
+            for x in 0..count {
+                if sender.send(x).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        ffi::Ok(Self { runtime, receiver: Mutex::new(receiver) })
+    }
+
+    /// Waits for the next item, or `None` once all items were sent.
+    pub async fn recv_async(this: AsyncSelf<Self>) -> ffi::Result<ffi::Option<u32>, FFIError> {
+        let item = this.receiver.lock().await.recv().await;
+        ffi::Ok(item.into())
+    }
+}
+
+impl AsyncRuntime for ServiceChannel {
+    fn spawn<Fn, F>(&self, f: Fn)
+    where
+        Fn: FnOnce(()) -> F,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.runtime.spawn(f(()));
+    }
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(pattern!(ServiceChannel)).validate().build()
+}
+
+#[test]
+fn channel_is_detected() {
+    let inventory = ffi_inventory();
+    let service = inventory.patterns().iter().find_map(|x| match x {
+        interoptopus::pattern::LibraryPattern::Service(x) => Some(x),
+        _ => None,
+    });
+
+    let channel = service.unwrap().channel().unwrap();
+
+    assert_eq!(channel.recv().name(), "service_channel_recv_async");
+    assert_eq!(channel.item(), &interoptopus::lang::Type::Primitive(interoptopus::lang::Primitive::U32));
+}
+
+#[test]
+fn async_enumerable_from_channel() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(
+        generated.contains("public async IAsyncEnumerable<uint> ReadAllAsync([EnumeratorCancellation] System.Threading.CancellationToken cancellationToken = default)")
+    );
+    assert!(generated.contains("OptionU32 item;"));
+    assert!(generated.contains("try { item = await RecvAsync().WaitAsync(cancellationToken); }"));
+    assert!(generated.contains("catch (OperationCanceledException) when (cancellationToken.IsCancellationRequested) { Dispose(); throw; }"));
+    assert!(generated.contains("if (item.IsNone) yield break;"));
+    assert!(generated.contains("yield return item.AsSome();"));
+    assert!(!generated.contains("if (cancellationToken.IsCancellationRequested) Dispose();"));
+
+    validate_output!("tests", "csharp_service_channel.cs", generated.as_str());
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi, ffi_service, ffi_type, pattern};
+use interoptopus_backend_csharp::Interop;
+use interoptopus_reference_project::patterns::result::Error as FFIError;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type(opaque)]
+pub struct ServiceFallible {
+    value: u32,
+}
+
+#[ffi_service]
+impl ServiceFallible {
+    pub fn new_checked(x: u32) -> ffi::Result<Self, FFIError> {
+        if x == 0 {
+            ffi::Result::Err(FFIError::Fail)
+        } else {
+            ffi::Ok(Self { value: x })
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[ffi_type(opaque)]
+pub struct ServiceColliding {}
+
+#[ffi_service]
+impl ServiceColliding {
+    pub fn new() -> ffi::Result<Self, FFIError> {
+        ffi::Ok(Self {})
+    }
+
+    pub fn try_new() -> ffi::Result<Self, FFIError> {
+        ffi::Ok(Self {})
+    }
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(pattern!(ServiceFallible)).validate().build()
+}
+
+#[test]
+fn fallible_constructors() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("public static ServiceFallible NewChecked(uint x)"));
+    assert!(generated.contains("var result = Interop.service_fallible_new_checked(x);"));
+    assert!(generated.contains("if (!result.IsOk) throw new InteropException<Error>(result.AsErr());"));
+    assert!(generated.contains("public static bool TryNewChecked(uint x, out ServiceFallible service) => TryNewChecked(x, out service, out _);"));
+    assert!(generated.contains("public static bool TryNewChecked(uint x, out ServiceFallible service, out Error error)"));
+    assert!(generated.contains("error = result.AsErr();"));
+    assert!(generated.contains("public class InteropException<T> : InteropException"));
+
+    validate_output!("tests", "csharp_service_ctors.cs", generated.as_str());
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "can not emit `TryNew` for `service_colliding_new`, it collides with `service_colliding_try_new`")]
+fn colliding_try_constructor() {
+    let inventory = Inventory::builder().register(pattern!(ServiceColliding)).validate().build();
+    let interop = Interop::builder()
+        .inventory(inventory)
+        .namespace_mappings(common_namespace_mappings())
+        .build()
+        .unwrap();
+
+    let _ = interop.to_string();
+}
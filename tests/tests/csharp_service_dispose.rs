@@ -0,0 +1,50 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi, ffi_service, ffi_type, pattern};
+use interoptopus_backend_csharp::Interop;
+use interoptopus_reference_project::patterns::result::Error as FFIError;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type(opaque)]
+pub struct ServiceDisposable {
+    x: u32,
+}
+
+#[ffi_service]
+impl ServiceDisposable {
+    pub fn new() -> ffi::Result<Self, FFIError> {
+        ffi::Ok(Self { x: 0 })
+    }
+
+    pub fn get(&self) -> u32 {
+        self.x
+    }
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(pattern!(ServiceDisposable)).validate().build()
+}
+
+#[test]
+fn dispose_pattern() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("public partial class ServiceDisposable : IDisposable"));
+    assert!(generated.contains("public void Dispose()"));
+    assert!(generated.contains("GC.SuppressFinalize(this);"));
+    assert!(generated.contains("protected virtual void Dispose(bool disposing)"));
+    assert!(generated.contains("~ServiceDisposable()"));
+    assert!(generated.contains("Dispose(false);"));
+    assert!(generated.contains("try { return Interop.service_disposable_get(_context); }"));
+    assert!(generated.contains("finally { GC.KeepAlive(this); }"));
+
+    validate_output!("tests", "csharp_service_dispose.cs", generated.as_str());
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{callback, ffi, ffi_service, ffi_type, pattern};
+use interoptopus_backend_csharp::Interop;
+use interoptopus_reference_project::patterns::result::Error as FFIError;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+callback!(TickCallback(ticks: u64, delta: f32));
+
+#[ffi_type(opaque)]
+pub struct ServiceEvents {
+    tick: Option<TickCallback>,
+}
+
+#[ffi_service]
+impl ServiceEvents {
+    pub fn new() -> ffi::Result<Self, FFIError> {
+        ffi::Ok(Self { tick: None })
+    }
+
+    /// Called on every simulation tick.
+    pub fn register_tick(&mut self, callback: TickCallback) -> ffi::Result<(), FFIError> {
+        self.tick = Some(callback);
+        ffi::Ok(())
+    }
+
+    pub fn unregister_tick(&mut self) -> ffi::Result<(), FFIError> {
+        self.tick = None;
+        ffi::Ok(())
+    }
+
+    pub fn step(&mut self, ticks: u64) {
+        if let Some(tick) = &self.tick {
+            tick.call(ticks, 1.0);
+        }
+    }
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(pattern!(ServiceEvents)).validate().build()
+}
+
+#[test]
+fn events_are_detected() {
+    let inventory = ffi_inventory();
+    let service = inventory.patterns().iter().find_map(|x| match x {
+        interoptopus::pattern::LibraryPattern::Service(x) => Some(x),
+        _ => None,
+    });
+
+    let events = service.unwrap().events();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name(), "tick");
+    assert_eq!(events[0].callback().name(), "TickCallback");
+}
+
+#[test]
+fn event_from_register_unregister() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("public class TickEventArgs : EventArgs"));
+    assert!(generated.contains("public event EventHandler<TickEventArgs> OnTick"));
+    assert!(generated.contains("var callback = new TickCallback((ticks, delta) => _onTick?.Invoke(this, new TickEventArgs(ticks, delta)));"));
+    assert!(generated.contains("var handle = GCHandle.Alloc(callback);"));
+    assert!(generated.contains("try { Interop.service_events_register_tick(_context, callback).AsOk(); }"));
+    assert!(generated.contains("try { Interop.service_events_unregister_tick(_context).AsOk(); }"));
+    assert!(generated.contains("catch { _onTick += value; throw; }"));
+    assert!(generated.contains("var callback = (TickCallback)_onTickHandle.Target;"));
+    assert!(generated.contains("callback.Dispose();"));
+    assert!(generated.contains("onTickCallback = (TickCallback)_onTickHandle.Target;"));
+    assert!(generated.contains("onTickCallback?.Dispose();"));
+
+    validate_output!("tests", "csharp_service_events.cs", generated.as_str());
+
+    Ok(())
+}
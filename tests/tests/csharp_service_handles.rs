@@ -0,0 +1,73 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{ffi, ffi_function, ffi_service, ffi_type, function, pattern};
+use interoptopus_backend_csharp::Interop;
+use interoptopus_reference_project::patterns::result::Error as FFIError;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type(opaque)]
+pub struct ServiceUnchecked {}
+
+#[ffi_service]
+impl ServiceUnchecked {
+    pub fn new() -> ffi::Result<Self, FFIError> {
+        ffi::Ok(Self {})
+    }
+}
+
+#[ffi_type(opaque)]
+pub struct ServiceChecked {}
+
+#[ffi_service]
+impl ServiceChecked {
+    pub fn new() -> ffi::Result<Self, FFIError> {
+        ffi::Ok(Self {})
+    }
+}
+
+// Does not take a handle, so it must not be used to validate `ServiceUnchecked` handles.
+#[ffi_function]
+pub fn service_unchecked_is_valid(_x: u32) -> bool {
+    true
+}
+
+#[ffi_function]
+pub fn service_checked_is_valid(_handle: *const ServiceChecked) -> bool {
+    true
+}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder()
+        .register(function!(service_unchecked_is_valid))
+        .register(function!(service_checked_is_valid))
+        .register(pattern!(ServiceUnchecked))
+        .register(pattern!(ServiceChecked))
+        .validate()
+        .build()
+}
+
+#[test]
+fn try_from_handle() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("public static ServiceUnchecked FromHandle(IntPtr handle)"));
+    assert!(generated.contains("public static bool TryFromHandle(IntPtr handle, out ServiceUnchecked service)"));
+    assert!(generated.contains("/// Without a native `service_unchecked_is_valid` function this can only check for null handles."));
+    assert!(!generated.contains("if (!Interop.service_unchecked_is_valid(handle)) return false;"));
+
+    assert!(generated.contains("public static ServiceChecked FromHandle(IntPtr handle)"));
+    assert!(generated.contains("public static bool TryFromHandle(IntPtr handle, out ServiceChecked service)"));
+    assert!(generated.contains("if (!Interop.service_checked_is_valid(handle)) return false;"));
+    assert!(generated.contains("if (handle == IntPtr.Zero) return false;"));
+    assert!(generated.contains("service = FromHandle(handle);"));
+
+    validate_output!("tests", "csharp_service_handles.cs", generated.as_str());
+
+    Ok(())
+}
@@ -0,0 +1,1463 @@
+// <auto-generated>
+//
+// This file was automatically generated by Interoptopus.
+//
+// Library:      library
+// Hash:         0xcb7a1a579a71195a
+// Namespace:    
+// Builder:      interoptopus_backend_csharp
+//
+// Do not edit this file manually.
+//
+// </auto-generated>
+
+
+#pragma warning disable 0105
+using System;
+using System.Text;
+using System.Threading.Tasks;
+using System.Collections;
+using System.Collections.Generic;
+using System.Runtime.InteropServices;
+using System.Runtime.InteropServices.Marshalling;
+using System.Runtime.CompilerServices;
+using My.Company;
+using My.Company.Common;
+#pragma warning restore 0105
+
+namespace My.Company
+{
+    public static partial class Interop
+    {
+        public const string NativeLib = "library";
+
+        static Interop()
+        {
+        }
+
+
+
+        [LibraryImport(NativeLib, EntryPoint = "slice_blittable")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void slice_blittable(SlicePacked2 ignored);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "slice_marshalled")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void slice_marshalled(SliceNamed ignored);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "slice_option")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void slice_option(SliceWithOption ignored);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "slice_pointer_sized")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void slice_pointer_sized(SlicePointerSized ignored);
+
+
+        [LibraryImport(NativeLib, EntryPoint = "slice_primitive")]
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static partial void slice_primitive(SliceU8 ignored);
+
+
+    }
+
+    public partial class Named
+    {
+        public Utf8String name;
+        public ulong id;
+        public Named(Utf8String _name, ulong _id)
+        {
+            name = _name;
+            id = _id;
+        }
+
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class Named : IDisposable
+    {
+        public Named() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged IntoUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.name = name.IntoUnmanaged();
+            _unmanaged.id = id;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.name = name.AsUnmanaged();
+            _unmanaged.id = id;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public Utf8String.Unmanaged name;
+            public ulong id;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Named IntoManaged()
+            {
+                var _managed = new Named();
+                _managed.name = name.IntoManaged();
+                _managed.id = id;
+                return _managed;
+            }
+        }
+
+        public void Dispose()
+        {
+            name.Dispose();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Named { ... }";
+        }
+
+        [CustomMarshaller(typeof(Named), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Named _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Named managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Named managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.IntoUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Named ToManaged() { return _unmanaged.IntoManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct Packed2
+    {
+        public byte x;
+        public uint y;
+        public Packed2(byte _x, uint _y)
+        {
+            x = _x;
+            y = _y;
+        }
+
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct Packed2 
+    {
+        public Packed2() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x = x;
+            _unmanaged.y = y;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.x = x;
+            _unmanaged.y = y;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public byte x;
+            public uint y;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe Packed2 ToManaged()
+            {
+                var _managed = new Packed2();
+                _managed.x = x;
+                _managed.y = y;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "Packed2 { ... }";
+        }
+
+        [CustomMarshaller(typeof(Packed2), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private Packed2 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Packed2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Packed2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Packed2 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct PointerSized
+    {
+        public nuint len;
+        public PointerSized(nuint _len)
+        {
+            len = _len;
+        }
+
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct PointerSized 
+    {
+        public PointerSized() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.len = len;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.len = len;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public nuint len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe PointerSized ToManaged()
+            {
+                var _managed = new PointerSized();
+                _managed.len = len;
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "PointerSized { ... }";
+        }
+
+        [CustomMarshaller(typeof(PointerSized), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private PointerSized _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(PointerSized managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(PointerSized managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public PointerSized ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial struct WithOption
+    {
+        public OptionU32 id;
+        public WithOption(OptionU32 _id)
+        {
+            id = _id;
+        }
+
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct WithOption 
+    {
+        public WithOption() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.id = id.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal unsafe Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged.id = id.ToUnmanaged();
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public OptionU32.Unmanaged id;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe WithOption ToManaged()
+            {
+                var _managed = new WithOption();
+                _managed.id = id.ToManaged();
+                return _managed;
+            }
+        }
+
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            return "WithOption { ... }";
+        }
+
+        [CustomMarshaller(typeof(WithOption), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+        public ref struct Marshaller
+        {
+            private WithOption _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(WithOption managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(WithOption managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public WithOption ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+    public partial class SliceNamed
+    {
+        IntPtr _data;
+        ulong _len;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SliceNamed : IDisposable
+    {
+        public int Count => (int) _len;
+
+        public unsafe Named this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= (int) _len) throw new IndexOutOfRangeException();
+                if (_data == IntPtr.Zero) { throw new Exception(); }
+                // TODO
+                throw new Exception();
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        SliceNamed() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe SliceNamed From(Named[] managed)
+        {
+            var rval = new SliceNamed();
+            var size = sizeof(Named.Unmanaged);
+            rval._data  = Marshal.AllocHGlobal(size * managed.Length);
+            rval._len = (ulong) managed.Length;
+            for (var i = 0; i < managed.Length; ++i)
+            {
+                var unmanaged = managed[i].AsUnmanaged();
+                var dst = IntPtr.Add(rval._data, i * size);
+                Marshal.StructureToPtr(unmanaged, dst, false);
+            }
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_data == IntPtr.Zero) return;
+            Marshal.FreeHGlobal(_data);
+            _data = IntPtr.Zero;
+        }
+
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._data = _data;
+            unmanaged._len = _len; 
+            return unmanaged;
+        }
+
+
+        [CustomMarshaller(typeof(SliceNamed), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal unsafe SliceNamed ToManaged()
+            {
+                var _managed = new SliceNamed();
+                _managed._data = _data;
+                _managed._len = _len;
+                return _managed;
+            }
+
+        }
+
+        public ref struct Marshaller
+        {
+            private SliceNamed _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SliceNamed managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SliceNamed managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SliceNamed ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+        public static class SliceNamedExtensions
+        {
+            public static SliceNamed Slice(this Named[] s) { return SliceNamed.From(s); }
+        }
+
+
+    public partial class SlicePacked2
+    {
+        GCHandle _handle;
+        IntPtr _data;
+        ulong _len;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SlicePacked2 : IEnumerable<Packed2>, IDisposable
+    {
+        public int Count => (int) _len;
+
+        public unsafe ReadOnlySpan<Packed2> ReadOnlySpan
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => new(_data.ToPointer(), (int)_len);
+        }
+
+        public unsafe Packed2 this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                return Unsafe.Read<Packed2>((void*)IntPtr.Add(_data, i * Unsafe.SizeOf<Packed2>()));
+            }
+
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        SlicePacked2() { }
+
+        public static SlicePacked2 From(IntPtr data, ulong len)
+        {
+            var rval = new SlicePacked2();
+            rval._data = data;
+            rval._len = len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static SlicePacked2 From(Packed2[] managed)
+        {
+            AssertElementLayout();
+            var rval = new SlicePacked2();
+            rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
+            rval._data = rval._handle.AddrOfPinnedObject();
+            rval._len = (ulong) managed.Length;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IEnumerator<Packed2> GetEnumerator()
+        {
+            for (var i = 0; i < Count; ++i) { yield return this[i]; }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        IEnumerator IEnumerable.GetEnumerator() => GetEnumerator();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_handle is { IsAllocated: true }) { _handle.Free(); }
+            _data = IntPtr.Zero;
+        }
+
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._data = _data;
+            unmanaged._len = _len; 
+            return unmanaged;
+        }
+
+
+        [System.Diagnostics.Conditional("DEBUG")]
+        static void AssertElementLayout()
+        {
+            var size = Unsafe.SizeOf<Packed2>();
+            var align = (int) Marshal.OffsetOf<ElementAlignment>(nameof(ElementAlignment.element));
+            System.Diagnostics.Debug.Assert(size == 8, $"Size of 'Packed2' is {size} bytes in C#, but 8 in Rust.");
+            System.Diagnostics.Debug.Assert(align == 4, $"Alignment of 'Packed2' is {align} bytes in C#, but 4 in Rust.");
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        struct ElementAlignment
+        {
+            public byte padding;
+            public Packed2 element;
+        }
+
+        [CustomMarshaller(typeof(SlicePacked2), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal SlicePacked2 ToManaged()
+            {
+                return SlicePacked2.From(_data, _len);
+            }
+        }
+
+        public ref struct Marshaller
+        {
+            private SlicePacked2 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SlicePacked2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SlicePacked2 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SlicePacked2 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+        public static class SlicePacked2Extensions
+        {
+            public static SlicePacked2 Slice(this Packed2[] s) { return SlicePacked2.From(s); }
+        }
+
+
+    public partial class SlicePointerSized
+    {
+        GCHandle _handle;
+        IntPtr _data;
+        ulong _len;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SlicePointerSized : IEnumerable<PointerSized>, IDisposable
+    {
+        public int Count => (int) _len;
+
+        public unsafe ReadOnlySpan<PointerSized> ReadOnlySpan
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => new(_data.ToPointer(), (int)_len);
+        }
+
+        public unsafe PointerSized this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                return Unsafe.Read<PointerSized>((void*)IntPtr.Add(_data, i * Unsafe.SizeOf<PointerSized>()));
+            }
+
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        SlicePointerSized() { }
+
+        public static SlicePointerSized From(IntPtr data, ulong len)
+        {
+            var rval = new SlicePointerSized();
+            rval._data = data;
+            rval._len = len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static SlicePointerSized From(PointerSized[] managed)
+        {
+            var rval = new SlicePointerSized();
+            rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
+            rval._data = rval._handle.AddrOfPinnedObject();
+            rval._len = (ulong) managed.Length;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IEnumerator<PointerSized> GetEnumerator()
+        {
+            for (var i = 0; i < Count; ++i) { yield return this[i]; }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        IEnumerator IEnumerable.GetEnumerator() => GetEnumerator();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_handle is { IsAllocated: true }) { _handle.Free(); }
+            _data = IntPtr.Zero;
+        }
+
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._data = _data;
+            unmanaged._len = _len; 
+            return unmanaged;
+        }
+
+
+        [CustomMarshaller(typeof(SlicePointerSized), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal SlicePointerSized ToManaged()
+            {
+                return SlicePointerSized.From(_data, _len);
+            }
+        }
+
+        public ref struct Marshaller
+        {
+            private SlicePointerSized _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SlicePointerSized managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SlicePointerSized managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SlicePointerSized ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+        public static class SlicePointerSizedExtensions
+        {
+            public static SlicePointerSized Slice(this PointerSized[] s) { return SlicePointerSized.From(s); }
+        }
+
+
+    public partial class SliceU8
+    {
+        GCHandle _handle;
+        IntPtr _data;
+        ulong _len;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SliceU8 : IEnumerable<byte>, IDisposable
+    {
+        public int Count => (int) _len;
+
+        public unsafe ReadOnlySpan<byte> ReadOnlySpan
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => new(_data.ToPointer(), (int)_len);
+        }
+
+        public unsafe byte this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                return Unsafe.Read<byte>((void*)IntPtr.Add(_data, i * Unsafe.SizeOf<byte>()));
+            }
+
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        SliceU8() { }
+
+        public static SliceU8 From(IntPtr data, ulong len)
+        {
+            var rval = new SliceU8();
+            rval._data = data;
+            rval._len = len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static SliceU8 From(byte[] managed)
+        {
+            var rval = new SliceU8();
+            rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
+            rval._data = rval._handle.AddrOfPinnedObject();
+            rval._len = (ulong) managed.Length;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IEnumerator<byte> GetEnumerator()
+        {
+            for (var i = 0; i < Count; ++i) { yield return this[i]; }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        IEnumerator IEnumerable.GetEnumerator() => GetEnumerator();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_handle is { IsAllocated: true }) { _handle.Free(); }
+            _data = IntPtr.Zero;
+        }
+
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._data = _data;
+            unmanaged._len = _len; 
+            return unmanaged;
+        }
+
+
+        [CustomMarshaller(typeof(SliceU8), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal SliceU8 ToManaged()
+            {
+                return SliceU8.From(_data, _len);
+            }
+        }
+
+        public ref struct Marshaller
+        {
+            private SliceU8 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SliceU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SliceU8 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SliceU8 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+        public static class SliceU8Extensions
+        {
+            public static SliceU8 Slice(this byte[] s) { return SliceU8.From(s); }
+        }
+
+
+    public partial class SliceWithOption
+    {
+        GCHandle _handle;
+        IntPtr _data;
+        ulong _len;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class SliceWithOption : IEnumerable<WithOption>, IDisposable
+    {
+        public int Count => (int) _len;
+
+        public unsafe ReadOnlySpan<WithOption> ReadOnlySpan
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get => new(_data.ToPointer(), (int)_len);
+        }
+
+        public unsafe WithOption this[int i]
+        {
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            get
+            {
+                if (i >= Count) throw new IndexOutOfRangeException();
+                return Unsafe.Read<WithOption>((void*)IntPtr.Add(_data, i * Unsafe.SizeOf<WithOption>()));
+            }
+
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        SliceWithOption() { }
+
+        public static SliceWithOption From(IntPtr data, ulong len)
+        {
+            var rval = new SliceWithOption();
+            rval._data = data;
+            rval._len = len;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static SliceWithOption From(WithOption[] managed)
+        {
+            AssertElementLayout();
+            var rval = new SliceWithOption();
+            rval._handle = GCHandle.Alloc(managed, GCHandleType.Pinned);
+            rval._data = rval._handle.AddrOfPinnedObject();
+            rval._len = (ulong) managed.Length;
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public IEnumerator<WithOption> GetEnumerator()
+        {
+            for (var i = 0; i < Count; ++i) { yield return this[i]; }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        IEnumerator IEnumerable.GetEnumerator() => GetEnumerator();
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_handle is { IsAllocated: true }) { _handle.Free(); }
+            _data = IntPtr.Zero;
+        }
+
+        internal Unmanaged ToUnmanaged()
+        {
+            var unmanaged = new Unmanaged();
+            unmanaged._data = _data;
+            unmanaged._len = _len; 
+            return unmanaged;
+        }
+
+
+        [System.Diagnostics.Conditional("DEBUG")]
+        static void AssertElementLayout()
+        {
+            var size = Unsafe.SizeOf<WithOption>();
+            var align = (int) Marshal.OffsetOf<ElementAlignment>(nameof(ElementAlignment.element));
+            System.Diagnostics.Debug.Assert(size == 8, $"Size of 'WithOption' is {size} bytes in C#, but 8 in Rust.");
+            System.Diagnostics.Debug.Assert(align == 4, $"Alignment of 'WithOption' is {align} bytes in C#, but 4 in Rust.");
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        struct ElementAlignment
+        {
+            public byte padding;
+            public WithOption element;
+        }
+
+        [CustomMarshaller(typeof(SliceWithOption), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            public IntPtr _data;
+            public ulong _len;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal SliceWithOption ToManaged()
+            {
+                return SliceWithOption.From(_data, _len);
+            }
+        }
+
+        public ref struct Marshaller
+        {
+            private SliceWithOption _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(SliceWithOption managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(SliceWithOption managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public SliceWithOption ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+        public static class SliceWithOptionExtensions
+        {
+            public static SliceWithOption Slice(this WithOption[] s) { return SliceWithOption.From(s); }
+        }
+
+
+    ///Option that contains Some(value) or None.
+    public partial struct OptionU32
+    {
+        uint _variant;
+        uint _Some;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct OptionU32 
+    {
+        [StructLayout(LayoutKind.Sequential)]
+        internal unsafe struct UnmanagedSome
+        {
+            internal uint _variant;
+            internal uint _Some;
+        }
+
+
+
+        [StructLayout(LayoutKind.Explicit)]
+        public unsafe struct Unmanaged
+        {
+            [FieldOffset(0)]
+            internal uint _variant;
+
+            [FieldOffset(0)]
+            internal UnmanagedSome _Some;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            internal OptionU32 ToManaged()
+            {
+                var _managed = new OptionU32();
+                _managed._variant = _variant;
+                if (_variant == 0) _managed._Some = _Some._Some;
+                return _managed;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged ToUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Some._Some = _Some;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        internal Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._variant = _variant;
+            if (_variant == 0) _unmanaged._Some._Some = _Some;
+            return _unmanaged;
+        }
+
+        public static OptionU32 Some(uint value) => new() { _variant = 0, _Some = value };
+        public static OptionU32 None => new() { _variant = 1 };
+
+        public bool IsSome => _variant == 0;
+        public bool IsNone => _variant == 1;
+
+        public uint AsSome() { if (_variant != 0) { throw new InteropException(); } else { return _Some; } }
+        public void AsNone() { if (_variant != 1) throw new InteropException(); }
+
+        #nullable enable
+        public uint? AsSomeOrNull() => _variant == 0 ? _Some : null;
+        #nullable disable
+
+        public uint AsSomeOrElse(Func<OptionU32, uint> cb) => _variant == 0 ? _Some : cb(this);
+        public void AsNoneOrElse(Action<OptionU32> cb) { if (_variant != 1) cb(this); }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public override string ToString()
+        {
+            if (_variant == 0) return "Some(...)";
+            if (_variant == 1) return "None";
+            throw new InteropException();
+        }
+
+        [CustomMarshaller(typeof(OptionU32), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private OptionU32 _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(OptionU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(OptionU32 managed) { _managed = managed; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged() { return _managed.ToUnmanaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public OptionU32 ToManaged() { return _unmanaged.ToManaged(); }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() {}
+        }
+    }
+
+
+
+    public class InteropException : Exception
+    {
+        public InteropException() : base()
+        {
+        }
+    }
+
+    public class InteropException<T> : InteropException
+    {
+        public T Error { get; private set; }
+
+        public InteropException(T error) : base()
+        {
+            Error = error;
+        }
+    }
+    [UnmanagedFunctionPointer(CallingConvention.Cdecl)]
+    public delegate void AsyncHelperNative(IntPtr data, IntPtr callback_data);
+    public delegate void AsyncHelperDelegate(IntPtr data);
+
+    public partial struct AsyncHelper
+    {
+        private AsyncHelperDelegate _managed;
+        private AsyncHelperNative _native;
+        private IntPtr _ptr;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial struct AsyncHelper : IDisposable
+    {
+        public AsyncHelper() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public AsyncHelper(AsyncHelperDelegate managed)
+        {
+            _managed = managed;
+            _native = Call;
+            _ptr = Marshal.GetFunctionPointerForDelegate(_native);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        void Call(IntPtr data, IntPtr _)
+        {
+            _managed(data);
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            Marshal.FreeHGlobal(_ptr);
+            _ptr = IntPtr.Zero;
+        }
+
+        [CustomMarshaller(typeof(AsyncHelper), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public struct Unmanaged
+        {
+            internal IntPtr Callback;
+            internal IntPtr Data;
+        }
+
+        public ref struct Marshaller
+        {
+            private AsyncHelper _managed;
+            private Unmanaged _unmanaged;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(AsyncHelper managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Unmanaged ToUnmanaged()
+            {
+                _unmanaged = new Unmanaged();
+                _unmanaged.Callback = _managed._ptr;
+                _unmanaged.Data = IntPtr.Zero;
+                return _unmanaged;
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public AsyncHelper ToManaged()
+            {
+                _managed = new AsyncHelper();
+                _managed._ptr = _unmanaged.Callback;
+                return _managed;
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() { }
+        }
+    }
+
+    public delegate void AsyncCallbackCommon(IntPtr data, IntPtr callback_data);
+
+    [StructLayout(LayoutKind.Sequential)]
+    public partial struct AsyncCallbackCommonNative
+    {
+        internal IntPtr _ptr;
+        internal IntPtr _ts;
+    }
+    public partial class Utf8String
+    {
+        IntPtr _ptr;
+        ulong _len;
+        ulong _capacity;
+    }
+
+    [NativeMarshalling(typeof(MarshallerMeta))]
+    public partial class Utf8String : IDisposable
+    {
+        private Utf8String() { }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String From(string s)
+        {
+            var rval = new Utf8String();
+            var source = s.AsSpan();
+
+            // Upper bound of the encoded size; short strings are encoded on the stack, others into a pooled buffer.
+            // The native side receives an explicit length, so no nul terminator is written.
+            var maxLen = Encoding.UTF8.GetMaxByteCount(source.Length);
+            byte[] rented = null;
+            Span<byte> utf8Bytes = maxLen <= 1024
+                ? stackalloc byte[maxLen]
+                : (rented = System.Buffers.ArrayPool<byte>.Shared.Rent(maxLen));
+
+            try
+            {
+                var len = Encoding.UTF8.GetBytes(source, utf8Bytes);
+
+                fixed (byte* p = utf8Bytes)
+                {
+                    InteropHelper.interoptopus_string_create((IntPtr)p, (ulong)len, out var native);
+                    rval._ptr = native._ptr;
+                    rval._len = native._len;
+                    rval._capacity = native._capacity;
+                }
+            }
+            finally
+            {
+                if (rented != null) System.Buffers.ArrayPool<byte>.Shared.Return(rented);
+            }
+
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public static unsafe Utf8String Empty()
+        {
+            InteropHelper.interoptopus_string_create(IntPtr.Zero, 0, out var _out);
+            return _out.IntoManaged();
+        }
+
+
+        public unsafe string String
+        {
+            get
+            {
+                var span = new ReadOnlySpan<byte>((byte*)_ptr, (int)_len);
+                var s = Encoding.UTF8.GetString(span);
+                return s;
+            }
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public string IntoString()
+        {
+            var rval = String;
+            Dispose();
+            return rval;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public void Dispose()
+        {
+            if (_ptr == IntPtr.Zero) return;
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            InteropHelper.interoptopus_string_destroy(_unmanaged);
+            _ptr = IntPtr.Zero;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Utf8String Clone()
+        {
+            var _new = new Unmanaged();
+            var _this = AsUnmanaged();
+            InteropHelper.interoptopus_string_clone(ref _this, ref _new);
+            return _new.IntoManaged();
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged IntoUnmanaged()
+        {
+            if (_ptr == IntPtr.Zero) { throw new Exception(); }
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            _ptr = IntPtr.Zero;
+            return _unmanaged;
+        }
+
+        [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+        public Unmanaged AsUnmanaged()
+        {
+            var _unmanaged = new Unmanaged();
+            _unmanaged._ptr = _ptr;
+            _unmanaged._len = _len;
+            _unmanaged._capacity = _capacity;
+            return _unmanaged;
+        }
+
+        [StructLayout(LayoutKind.Sequential)]
+        public unsafe struct Unmanaged
+        {
+            public IntPtr _ptr;
+            public ulong _len;
+            public ulong _capacity;
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Utf8String IntoManaged()
+            {
+                var _managed = new Utf8String();
+                _managed._ptr = _ptr;
+                _managed._len = _len;
+                _managed._capacity = _capacity;
+                return _managed;
+            }
+
+        }
+
+        public partial class InteropHelper
+        {
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_create")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_create(IntPtr utf8, ulong len, out Unmanaged rval);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_destroy")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_destroy(Unmanaged utf8);
+
+            [LibraryImport(Interop.NativeLib, EntryPoint = "interoptopus_string_clone")]
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public static partial long interoptopus_string_clone(ref Unmanaged orig, ref Unmanaged cloned);
+        }
+
+        [CustomMarshaller(typeof(Utf8String), MarshalMode.Default, typeof(Marshaller))]
+        private struct MarshallerMeta { }
+
+        public ref struct Marshaller
+        {
+            private Utf8String _managed; // Used when converting managed -> unmanaged
+            private Unmanaged _unmanaged; // Used when converting unmanaged -> managed
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Utf8String managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public Marshaller(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromManaged(Utf8String managed) { _managed = managed; }
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void FromUnmanaged(Unmanaged unmanaged) { _unmanaged = unmanaged; }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public unsafe Unmanaged ToUnmanaged()
+            {
+                return _managed.IntoUnmanaged();
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public unsafe Utf8String ToManaged()
+            {
+                return _unmanaged.IntoManaged();
+            }
+
+            [MethodImpl(MethodImplOptions.AggressiveOptimization)]
+            public void Free() { }
+        }
+    }
+
+    public static class StringExtensions
+    {
+        public static Utf8String Utf8(this string s) { return Utf8String.From(s); }
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::pattern::slice::Slice;
+use interoptopus::{ffi, ffi_function, ffi_type, function};
+use interoptopus_backend_csharp::Interop;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_type]
+#[derive(Copy, Clone)]
+pub struct Packed2 {
+    pub x: u8,
+    pub y: u32,
+}
+
+#[ffi_type]
+pub struct Named {
+    pub name: ffi::String,
+    pub id: u64,
+}
+
+#[ffi_type]
+pub struct WithOption {
+    pub id: ffi::Option<u32>,
+}
+
+#[ffi_type]
+#[derive(Copy, Clone)]
+pub struct PointerSized {
+    pub len: usize,
+}
+
+#[ffi_function]
+fn slice_blittable(_: Slice<Packed2>) {}
+
+#[ffi_function]
+fn slice_marshalled(_: Slice<Named>) {}
+
+#[ffi_function]
+fn slice_option(_: Slice<WithOption>) {}
+
+#[ffi_function]
+fn slice_pointer_sized(_: Slice<PointerSized>) {}
+
+#[ffi_function]
+fn slice_primitive(_: Slice<u8>) {}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder()
+        .register(function!(slice_blittable))
+        .register(function!(slice_marshalled))
+        .register(function!(slice_option))
+        .register(function!(slice_pointer_sized))
+        .register(function!(slice_primitive))
+        .build()
+}
+
+#[test]
+fn slice_layout_checks() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains(r#"System.Diagnostics.Debug.Assert(size == 8, $"Size of 'Packed2' is {size} bytes in C#, but 8 in Rust.");"#));
+    assert!(generated.contains(r#"System.Diagnostics.Debug.Assert(align == 4, $"Alignment of 'Packed2' is {align} bytes in C#, but 4 in Rust.");"#));
+    assert!(generated.contains("public WithOption element;"));
+    assert_eq!(generated.matches("static void AssertElementLayout()").count(), 2);
+
+    // Layouts depending on the pointer width would differ between 32 and 64 bit processes.
+    assert!(!generated.contains("Size of 'Named'"));
+    assert!(!generated.contains("Size of 'PointerSized'"));
+
+    validate_output!("tests", "csharp_slice_layout.cs", generated.as_str());
+
+    Ok(())
+}
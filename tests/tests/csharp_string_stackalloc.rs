@@ -0,0 +1,45 @@
+use anyhow::Error;
+use interoptopus::inventory::Bindings;
+use interoptopus::inventory::Inventory;
+use interoptopus::{builtins_string, ffi, ffi_function, function};
+use interoptopus_backend_csharp::Interop;
+use tests::backend_csharp::common_namespace_mappings;
+use tests::validate_output;
+
+#[ffi_function]
+fn print_string(_s: ffi::String) {}
+
+fn ffi_inventory() -> Inventory {
+    Inventory::builder().register(function!(print_string)).register(builtins_string!()).build()
+}
+
+#[test]
+fn stackalloc_threshold() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .string_stackalloc_threshold(256_u32)
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("Span<byte> utf8Bytes = maxLen <= 256"));
+    assert!(generated.contains("System.Buffers.ArrayPool<byte>.Shared.Return(rented);"));
+
+    validate_output!("tests", "csharp_string_stackalloc.cs", generated.as_str());
+
+    Ok(())
+}
+
+#[test]
+fn stackalloc_threshold_clamped() -> Result<(), Error> {
+    let generated = Interop::builder()
+        .inventory(ffi_inventory())
+        .namespace_mappings(common_namespace_mappings())
+        .string_stackalloc_threshold(u32::MAX)
+        .build()?
+        .to_string()?;
+
+    assert!(generated.contains("Span<byte> utf8Bytes = maxLen <= 2147483647"));
+
+    Ok(())
+}